@@ -0,0 +1,581 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::cmp::min;
+use core::mem;
+use core::str;
+
+use crate::error::{Component, ErrorKind, HttpError};
+use crate::headers::{Headers, HeadersRef};
+use crate::parser::{find_head_end, ParseStatus, ParserConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ChunkedState {
+    Size,
+    Data(usize),
+    DataEnd,
+    Trailer,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkedDecoder {
+    config: ParserConfig,
+    state: ChunkedState,
+    line: Vec<u8>,
+    decoded: usize,
+    position: usize,
+}
+
+impl Default for ChunkedDecoder {
+    fn default() -> Self {
+        Self::with_config(ParserConfig::default())
+    }
+}
+
+impl ChunkedDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            config,
+            state: ChunkedState::Size,
+            line: Vec::new(),
+            decoded: 0,
+            position: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_config(self.config);
+    }
+
+    pub fn decode(
+        &mut self,
+        src: &[u8],
+        body: &mut Vec<u8>,
+    ) -> Result<ParseStatus<Headers>, HttpError> {
+        let mut consumed = 0;
+
+        while consumed < src.len() {
+            if let ChunkedState::Data(remaining) = self.state {
+                let length = min(remaining, src.len() - consumed);
+
+                body.extend_from_slice(&src[consumed..consumed + length]);
+                consumed += length;
+                self.position += length;
+
+                self.state = if remaining == length {
+                    ChunkedState::DataEnd
+                } else {
+                    ChunkedState::Data(remaining - length)
+                };
+            } else {
+                self.line.push(src[consumed]);
+                consumed += 1;
+                self.position += 1;
+
+                let line_start = self.position - self.line.len();
+                let component = match self.state {
+                    ChunkedState::Size => Component::ChunkSize,
+                    _ => Component::ChunkData,
+                };
+
+                if let Some(trailers) = self
+                    .check_line_length()
+                    .and_then(|_| self.advance())
+                    .map_err(|error| error.offset(line_start).within(component))?
+                {
+                    return Ok(ParseStatus::Complete(trailers, consumed));
+                }
+            }
+        }
+
+        Ok(ParseStatus::Incomplete)
+    }
+
+    fn check_line_length(&self) -> Result<(), HttpError> {
+        if self.state == ChunkedState::Trailer {
+            if self.line.len() > self.config.max_head_size() {
+                return Err(HttpError::new(
+                    ErrorKind::HeaderFieldsTooLarge,
+                    "trailer section too large",
+                )
+                .offset(self.line.len() - 1)
+                .within(Component::Trailer(0)));
+            }
+        } else if self.line.len() > self.config.max_header_line_length.saturating_add(2) {
+            return Err(
+                HttpError::new(ErrorKind::InvalidChunk, "chunk line too long")
+                    .offset(self.line.len() - 1),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn advance(&mut self) -> Result<Option<Headers>, HttpError> {
+        match self.state {
+            ChunkedState::Size => {
+                if self.line.ends_with(b"\r\n") {
+                    let size = parse_chunk_size(&self.line[..self.line.len() - 2])?;
+
+                    if size > self.config.max_body_size - self.decoded {
+                        return Err(HttpError::new(
+                            ErrorKind::PayloadTooLarge,
+                            "chunked body exceeds maximum size",
+                        ));
+                    }
+
+                    self.decoded += size;
+                    self.line.clear();
+                    self.state = if size == 0 {
+                        ChunkedState::Trailer
+                    } else {
+                        ChunkedState::Data(size)
+                    };
+                }
+            }
+            ChunkedState::DataEnd => {
+                if self.line.len() == 2 {
+                    if self.line != b"\r\n" {
+                        return Err(HttpError::new(
+                            ErrorKind::InvalidChunk,
+                            "chunk data not followed by CRLF",
+                        ));
+                    }
+
+                    self.line.clear();
+                    self.state = ChunkedState::Size;
+                }
+            }
+            ChunkedState::Trailer => {
+                if self.line == b"\r\n" {
+                    self.reset();
+
+                    return Ok(Some(Headers::new()));
+                } else if self.line.ends_with(b"\r\n\r\n") {
+                    let trailers =
+                        str::from_utf8(&self.line[..self.line.len() - 4]).map_err(|_| {
+                            HttpError::new(ErrorKind::InvalidHeader, "trailer is not valid UTF-8")
+                                .within(Component::Trailer(0))
+                        })?;
+                    let trailers = Headers::from(
+                        &HeadersRef::parse(trailers, &self.config).map_err(as_trailer_error)?,
+                    );
+
+                    self.reset();
+
+                    return Ok(Some(trailers));
+                }
+            }
+            ChunkedState::Data(_) => {}
+        }
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Chunks<'a> {
+    src: &'a [u8],
+    chunked: bool,
+}
+
+impl<'a> Chunks<'a> {
+    pub fn new(src: &'a [u8], chunked: bool) -> Self {
+        Self { src, chunked }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.chunked {
+            return Some(mem::take(&mut self.src)).filter(|src| !src.is_empty());
+        }
+
+        let line_end = self.src.windows(2).position(|window| window == b"\r\n")?;
+        let size = parse_chunk_size(&self.src[..line_end]).ok()?;
+        let data_start = line_end + 2;
+
+        let remaining = self.src.len() - data_start;
+
+        if size == 0 || remaining < 2 || size > remaining - 2 {
+            self.src = &[];
+            return None;
+        }
+
+        let data = &self.src[data_start..data_start + size];
+        self.src = &self.src[data_start + size + 2..];
+
+        Some(data)
+    }
+}
+
+pub fn encode_chunk(src: &[u8]) -> Vec<u8> {
+    let mut data = format!("{:X}\r\n", src.len()).into_bytes();
+
+    data.extend_from_slice(src);
+    data.extend_from_slice(b"\r\n");
+
+    data
+}
+
+pub fn encode_last_chunk(trailers: Option<&Headers>) -> Vec<u8> {
+    let mut data = b"0\r\n".to_vec();
+
+    if let Some(trailers) = trailers {
+        if !trailers.is_empty() {
+            data.extend_from_slice(trailers.to_string().as_bytes());
+            data.extend_from_slice(b"\r\n");
+        }
+    }
+
+    data.extend_from_slice(b"\r\n");
+
+    data
+}
+
+pub fn encode(src: &[u8]) -> Vec<u8> {
+    encode_with_trailers(src, &Headers::new())
+}
+
+pub fn encode_with_trailers(src: &[u8], trailers: &Headers) -> Vec<u8> {
+    let mut data = if src.is_empty() {
+        Vec::new()
+    } else {
+        encode_chunk(src)
+    };
+
+    data.extend_from_slice(&encode_last_chunk(Some(trailers)));
+
+    data
+}
+
+pub(crate) fn find_end(src: &[u8], max_body_size: usize) -> Result<Option<usize>, HttpError> {
+    let mut position = 0;
+    let mut decoded: usize = 0;
+
+    loop {
+        let line_end = if let Some(index) = src[position..]
+            .windows(2)
+            .position(|window| window == b"\r\n")
+        {
+            position + index
+        } else {
+            return Ok(None);
+        };
+
+        let size = parse_chunk_size(&src[position..line_end])
+            .map_err(|error| error.offset(position).within(Component::ChunkSize))?;
+
+        if size > max_body_size - decoded {
+            return Err(HttpError::new(
+                ErrorKind::PayloadTooLarge,
+                "chunked body exceeds maximum size",
+            )
+            .offset(position)
+            .within(Component::ChunkSize));
+        }
+
+        decoded += size;
+        position = line_end + 2;
+
+        if size == 0 {
+            if src[position..].starts_with(b"\r\n") {
+                return Ok(Some(position + 2));
+            }
+
+            return Ok(find_head_end(src, position));
+        }
+
+        if size > src.len() - position || src.len() - position - size < 2 {
+            return Ok(None);
+        }
+
+        if &src[position + size..position + size + 2] != b"\r\n" {
+            return Err(
+                HttpError::new(ErrorKind::InvalidChunk, "chunk data not followed by CRLF")
+                    .offset(position + size)
+                    .within(Component::ChunkData),
+            );
+        }
+
+        position += size + 2;
+    }
+}
+
+fn parse_chunk_size(src: &[u8]) -> Result<usize, HttpError> {
+    let src = str::from_utf8(src)
+        .map_err(|_| HttpError::new(ErrorKind::InvalidChunk, "chunk size is not valid UTF-8"))?;
+
+    let size = if let Some(index) = src.find(';') {
+        &src[..index]
+    } else {
+        src
+    }
+    .trim_end_matches([' ', '\t']);
+
+    if size.is_empty() || !size.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(HttpError::new(
+            ErrorKind::InvalidChunk,
+            "chunk size is not a hexadecimal number",
+        ));
+    }
+
+    usize::from_str_radix(size, 16)
+        .map_err(|_| HttpError::new(ErrorKind::InvalidChunk, "chunk size overflows"))
+}
+
+fn as_trailer_error(mut error: HttpError) -> HttpError {
+    if let Some(Component::Header(index)) = error.component {
+        error.component = Some(Component::Trailer(index));
+    }
+
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_single_test() {
+        let mut body = Vec::new();
+        assert_eq!(
+            ChunkedDecoder::new().decode(b"4\r\nBody\r\n0\r\n\r\n", &mut body),
+            Ok(ParseStatus::Complete(Headers::new(), 14))
+        );
+        assert_eq!(body, b"Body");
+    }
+
+    #[test]
+    fn chunks_test() {
+        assert_eq!(
+            Chunks::new(
+                b"2;name=value\r\nBo\r\n2\r\ndy\r\n0\r\nTrailer-Name: Trailer-Value\r\n\r\n",
+                true
+            )
+            .collect::<Vec<_>>(),
+            [&b"Bo"[..], &b"dy"[..]]
+        );
+        assert_eq!(
+            Chunks::new(b"Body", false).collect::<Vec<_>>(),
+            [&b"Body"[..]]
+        );
+        assert_eq!(Chunks::new(b"", false).next(), None);
+    }
+
+    #[test]
+    fn chunks_oversized_test() {
+        assert_eq!(Chunks::new(b"FFFFFFFFFFFFFFFF\r\nab", true).count(), 0);
+        assert_eq!(Chunks::new(b"4\r\nBo", true).count(), 0);
+        assert_eq!(Chunks::new(b"2\r\nBo", true).count(), 0);
+    }
+
+    #[test]
+    fn decode_multiple_test() {
+        let mut body = Vec::new();
+        assert_eq!(
+            ChunkedDecoder::new().decode(b"2\r\nBo\r\nA\r\ndy01234567\r\n0\r\n\r\nNext", &mut body),
+            Ok(ParseStatus::Complete(Headers::new(), 27))
+        );
+        assert_eq!(body, b"Body01234567");
+    }
+
+    #[test]
+    fn decode_byte_by_byte_test() {
+        let src = b"4;name=value\r\nBody\r\n0\r\nTrailer-Name: Trailer-Value\r\n\r\n";
+
+        let mut headers = Headers::new();
+        headers.append("Trailer-Name", "Trailer-Value");
+
+        let mut decoder = ChunkedDecoder::new();
+        let mut body = Vec::new();
+        for byte in src[..src.len() - 1].chunks(1) {
+            assert_eq!(decoder.decode(byte, &mut body), Ok(ParseStatus::Incomplete));
+        }
+        assert_eq!(
+            decoder.decode(b"\n", &mut body),
+            Ok(ParseStatus::Complete(headers, 1))
+        );
+        assert_eq!(body, b"Body");
+    }
+
+    #[test]
+    fn encode_test() {
+        assert_eq!(encode(b"Body01234567"), b"C\r\nBody01234567\r\n0\r\n\r\n");
+    }
+
+    #[test]
+    fn encode_empty_test() {
+        assert_eq!(encode(b""), b"0\r\n\r\n");
+    }
+
+    #[test]
+    fn encode_last_chunk_trailers_test() {
+        let mut headers = Headers::new();
+        headers.append("Trailer-Name", "Trailer-Value");
+
+        assert_eq!(
+            encode_last_chunk(Some(&headers)),
+            b"0\r\nTrailer-Name: Trailer-Value\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn decode_encoded_test() {
+        let mut body = Vec::new();
+        assert_eq!(
+            ChunkedDecoder::new().decode(&encode(b"Body"), &mut body),
+            Ok(ParseStatus::Complete(Headers::new(), 14))
+        );
+        assert_eq!(body, b"Body");
+    }
+
+    #[test]
+    fn find_end_test() {
+        assert_eq!(
+            find_end(
+                b"2;name=value\r\nBo\r\n2\r\ndy\r\n0\r\n\r\nNext",
+                usize::MAX
+            ),
+            Ok(Some(30))
+        );
+    }
+
+    #[test]
+    fn find_end_trailers_test() {
+        assert_eq!(
+            find_end(
+                b"4\r\nBody\r\n0\r\nTrailer-Name: Trailer-Value\r\n\r\n",
+                usize::MAX
+            ),
+            Ok(Some(43))
+        );
+    }
+
+    #[test]
+    fn find_end_incomplete_test() {
+        assert_eq!(find_end(b"4\r\nBody\r\n0\r\n\r", usize::MAX), Ok(None));
+    }
+
+    #[test]
+    fn find_end_invalid_data_end_test() {
+        assert_eq!(
+            find_end(b"3\r\nBody\r\n0\r\n\r\n", usize::MAX).map_err(|error| error.kind),
+            Err(ErrorKind::InvalidChunk)
+        );
+    }
+
+    #[test]
+    fn find_end_too_large_test() {
+        assert_eq!(
+            find_end(b"2\r\nBo\r\n3\r\ndy!\r\n0\r\n\r\n", 4).map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn decode_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 4,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            ChunkedDecoder::with_config(config)
+                .decode(b"2\r\nBo\r\n3\r\ndy!\r\n0\r\n\r\n", &mut Vec::new())
+                .map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn decode_size_line_too_long_test() {
+        let config = ParserConfig {
+            max_header_line_length: 8,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            ChunkedDecoder::with_config(config)
+                .decode(b"4;name=value\r\nBody\r\n0\r\n\r\n", &mut Vec::new())
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidChunk)
+        );
+    }
+
+    #[test]
+    fn decode_too_many_trailers_test() {
+        let config = ParserConfig {
+            max_headers: 1,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            ChunkedDecoder::with_config(config)
+                .decode(
+                    b"0\r\nTrailer-Name1: Trailer-Value1\r\nTrailer-Name2: Trailer-Value2\r\n\r\n",
+                    &mut Vec::new()
+                )
+                .map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn decode_invalid_size_test() {
+        assert_eq!(
+            ChunkedDecoder::new()
+                .decode(b"x\r\nBody\r\n0\r\n\r\n", &mut Vec::new())
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidChunk)
+        );
+    }
+
+    #[test]
+    fn decode_invalid_data_end_test() {
+        assert_eq!(
+            ChunkedDecoder::new()
+                .decode(b"3\r\nBody\r\n0\r\n\r\n", &mut Vec::new())
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidChunk)
+        );
+    }
+
+    #[test]
+    fn decode_error_position_test() {
+        let mut decoder = ChunkedDecoder::new();
+        let mut body = Vec::new();
+        assert_eq!(
+            decoder.decode(b"4\r\nBody\r\n", &mut body),
+            Ok(ParseStatus::Incomplete)
+        );
+
+        let error = decoder.decode(b"x\r\n", &mut body).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidChunk);
+        assert_eq!(error.component, Some(Component::ChunkSize));
+        assert_eq!(error.position, Some(9));
+    }
+
+    #[test]
+    fn decode_trailer_error_test() {
+        let error = ChunkedDecoder::new()
+            .decode(b"0\r\nTrailer Name: Trailer-Value\r\n\r\n", &mut Vec::new())
+            .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidHeaderName);
+        assert_eq!(error.component, Some(Component::Trailer(0)));
+    }
+
+    #[test]
+    fn find_end_error_position_test() {
+        let error = find_end(b"3\r\nBody\r\n0\r\n\r\n", usize::MAX).unwrap_err();
+
+        assert_eq!(error.component, Some(Component::ChunkData));
+        assert_eq!(error.position, Some(6));
+    }
+}
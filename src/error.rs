@@ -1,5 +1,7 @@
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
-pub enum HttpError {
+use core::fmt;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ErrorKind {
     InvalidRequest,
     InvalidMethod,
     InvalidUri,
@@ -7,8 +9,179 @@ pub enum HttpError {
     InvalidAuthority,
     InvalidQuery,
     InvalidVersion,
+    UnsupportedVersion,
     InvalidHeader,
+    InvalidHeaderName,
+    InvalidHeaderValue,
+    ObsoleteLineFolding,
+    BareCarriageReturn,
+    BareLineFeed,
     InvalidResponse,
     InvalidStatus,
+    InvalidChunk,
+    UriTooLong,
+    HeaderFieldsTooLarge,
+    PayloadTooLarge,
+    InvalidRoute,
+    InvalidParameter,
     Exhausted,
 }
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Component {
+    RequestLine,
+    StatusLine,
+    Header(usize),
+    ChunkSize,
+    ChunkData,
+    Trailer(usize),
+    Body,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct HttpError {
+    pub kind: ErrorKind,
+    pub position: Option<usize>,
+    pub component: Option<Component>,
+    pub reason: Option<&'static str>,
+}
+
+impl HttpError {
+    pub fn new(kind: ErrorKind, reason: &'static str) -> Self {
+        Self {
+            kind,
+            position: None,
+            component: None,
+            reason: Some(reason),
+        }
+    }
+
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        self.position = Some(offset + self.position.unwrap_or(0));
+
+        self
+    }
+
+    pub(crate) fn within(mut self, component: Component) -> Self {
+        if self.component.is_none() {
+            self.component = Some(component);
+        }
+
+        self
+    }
+}
+
+impl From<ErrorKind> for HttpError {
+    fn from(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            position: None,
+            component: None,
+            reason: None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ErrorKind::InvalidRequest => "invalid request",
+            ErrorKind::InvalidMethod => "invalid method",
+            ErrorKind::InvalidUri => "invalid uri",
+            ErrorKind::InvalidScheme => "invalid scheme",
+            ErrorKind::InvalidAuthority => "invalid authority",
+            ErrorKind::InvalidQuery => "invalid query",
+            ErrorKind::InvalidVersion => "invalid version",
+            ErrorKind::UnsupportedVersion => "unsupported version",
+            ErrorKind::InvalidHeader => "invalid header",
+            ErrorKind::InvalidHeaderName => "invalid header name",
+            ErrorKind::InvalidHeaderValue => "invalid header value",
+            ErrorKind::ObsoleteLineFolding => "obsolete line folding",
+            ErrorKind::BareCarriageReturn => "bare carriage return",
+            ErrorKind::BareLineFeed => "bare line feed",
+            ErrorKind::InvalidResponse => "invalid response",
+            ErrorKind::InvalidStatus => "invalid status",
+            ErrorKind::InvalidChunk => "invalid chunk",
+            ErrorKind::UriTooLong => "uri too long",
+            ErrorKind::HeaderFieldsTooLarge => "header fields too large",
+            ErrorKind::PayloadTooLarge => "payload too large",
+            ErrorKind::InvalidRoute => "invalid route",
+            ErrorKind::InvalidParameter => "invalid parameter",
+            ErrorKind::Exhausted => "input exhausted",
+        })
+    }
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Component::RequestLine => f.write_str("request line"),
+            Component::StatusLine => f.write_str("status line"),
+            Component::Header(index) => write!(f, "header {}", index),
+            Component::ChunkSize => f.write_str("chunk size"),
+            Component::ChunkData => f.write_str("chunk data"),
+            Component::Trailer(index) => write!(f, "trailer {}", index),
+            Component::Body => f.write_str("body"),
+        }
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+
+        if let Some(component) = &self.component {
+            write!(f, " in {}", component)?;
+        }
+
+        if let Some(position) = self.position {
+            write!(f, " at byte {}", position)?;
+        }
+
+        if let Some(reason) = self.reason {
+            write!(f, ": {}", reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate alloc;
+
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        let error = HttpError::new(ErrorKind::InvalidHeaderName, "name is not a token")
+            .offset(4)
+            .within(Component::Header(1))
+            .offset(18);
+
+        assert_eq!(
+            error.to_string(),
+            "invalid header name in header 1 at byte 22: name is not a token"
+        );
+    }
+
+    #[test]
+    fn display_kind_test() {
+        assert_eq!(
+            HttpError::from(ErrorKind::Exhausted).to_string(),
+            "input exhausted"
+        );
+    }
+
+    #[test]
+    fn within_innermost_test() {
+        let error = HttpError::from(ErrorKind::InvalidChunk)
+            .within(Component::ChunkSize)
+            .within(Component::Body);
+
+        assert_eq!(error.component, Some(Component::ChunkSize));
+        assert_eq!(error.position, None);
+    }
+}
@@ -1,34 +1,107 @@
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{Component, ErrorKind, HttpError};
+use crate::parser::{check_line_breaks, is_token, ParserConfig};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Headers {
-    pub headers: BTreeMap<String, String>,
+    entries: Vec<(String, String)>,
+}
+
+impl Headers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    pub fn get_all<'a, 'b>(&'a self, name: &'b str) -> impl Iterator<Item = &'a str> + 'b
+    where
+        'a: 'b,
+    {
+        self.entries
+            .iter()
+            .filter(move |(entry_name, _)| entry_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.entries.push((name.to_string(), value.to_string()));
+    }
+
+    pub fn insert(&mut self, name: &str, value: &str) {
+        match self
+            .entries
+            .iter()
+            .position(|(entry_name, _)| entry_name.eq_ignore_ascii_case(name))
+        {
+            Some(index) => {
+                self.entries[index].1 = value.to_string();
+
+                let mut position = index + 1;
+                while position < self.entries.len() {
+                    if self.entries[position].0.eq_ignore_ascii_case(name) {
+                        self.entries.remove(position);
+                    } else {
+                        position += 1;
+                    }
+                }
+            }
+            None => self.append(name, value),
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let value = self.get(name).map(|value| value.to_string());
+
+        self.entries
+            .retain(|(entry_name, _)| !entry_name.eq_ignore_ascii_case(name));
+
+        value
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
 }
 
 impl TryFrom<&str> for Headers {
     type Error = HttpError;
 
     fn try_from(src: &str) -> Result<Self, Self::Error> {
-        let mut headers = BTreeMap::new();
+        let mut headers = Self::new();
 
-        for header_line in src.split("\r\n") {
-            let header_parts: Vec<&str> = header_line.split(": ").collect();
+        let mut line_start = 0;
 
-            if header_parts.len() != 2 {
-                return Err(HttpError::InvalidHeader);
-            }
+        for (index, header_line) in src.split("\r\n").enumerate() {
+            let (name, value) = parse_header_line(header_line)
+                .map_err(|error| error.offset(line_start).within(Component::Header(index)))?;
 
-            headers.insert(header_parts[0].to_string(), header_parts[1].to_string());
+            headers.append(name, value);
+            line_start += header_line.len() + 2;
         }
 
-        Ok(Self { headers })
+        Ok(headers)
     }
 }
 
@@ -36,17 +109,17 @@ impl ToString for Headers {
     fn to_string(&self) -> String {
         let mut data = String::new();
 
-        let mut iterator = self.headers.iter();
+        let mut iterator = self.iter();
 
-        if let Some((key, value)) = iterator.next() {
-            data += key;
+        if let Some((name, value)) = iterator.next() {
+            data += name;
             data += ": ";
             data += value;
         }
 
-        for (key, value) in iterator {
+        for (name, value) in iterator {
             data += "\r\n";
-            data += key;
+            data += name;
             data += ": ";
             data += value;
         }
@@ -55,48 +128,175 @@ impl ToString for Headers {
     }
 }
 
+impl From<&HeadersRef<'_>> for Headers {
+    fn from(headers_ref: &HeadersRef<'_>) -> Self {
+        let mut headers = Self::new();
+
+        for (name, value) in headers_ref.iter() {
+            headers.append(name, value);
+        }
+
+        headers
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeadersRef<'a> {
+    raw: &'a str,
+}
+
+impl<'a> HeadersRef<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.raw
+            .split("\r\n")
+            .filter(|header_line| !header_line.is_empty())
+            .filter_map(|header_line| parse_header_line(header_line).ok())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.get_all(name).next()
+    }
+
+    pub fn get_all<'b>(&self, name: &'b str) -> impl Iterator<Item = &'a str> + 'b
+    where
+        'a: 'b,
+    {
+        self.iter()
+            .filter(move |(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    pub(crate) fn component(&self, name: &str) -> Option<Component> {
+        self.iter()
+            .position(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(Component::Header)
+    }
+
+    pub fn parse(src: &'a str, config: &ParserConfig) -> Result<Self, HttpError> {
+        if !src.is_empty() {
+            let mut line_start = 0;
+
+            for (index, header_line) in src.split("\r\n").enumerate() {
+                if index >= config.max_headers {
+                    return Err(HttpError::new(
+                        ErrorKind::HeaderFieldsTooLarge,
+                        "too many header fields",
+                    )
+                    .offset(line_start)
+                    .within(Component::Header(index)));
+                } else if header_line.len() > config.max_header_line_length {
+                    return Err(HttpError::new(
+                        ErrorKind::HeaderFieldsTooLarge,
+                        "header line too long",
+                    )
+                    .offset(line_start + config.max_header_line_length)
+                    .within(Component::Header(index)));
+                }
+
+                parse_header_line(header_line)
+                    .map_err(|error| error.offset(line_start).within(Component::Header(index)))?;
+
+                line_start += header_line.len() + 2;
+            }
+        }
+
+        Ok(Self { raw: src })
+    }
+}
+
+impl<'a> TryFrom<&'a str> for HeadersRef<'a> {
+    type Error = HttpError;
+
+    fn try_from(src: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(src, &ParserConfig::default())
+    }
+}
+
+fn parse_header_line(src: &str) -> Result<(&str, &str), HttpError> {
+    if src.starts_with([' ', '\t']) {
+        return Err(HttpError::new(
+            ErrorKind::ObsoleteLineFolding,
+            "header line starts with whitespace",
+        ));
+    }
+
+    check_line_breaks(src)?;
+
+    let (name, value) = if let Some(index) = src.find(':') {
+        (&src[..index], &src[index + 1..])
+    } else {
+        return Err(HttpError::new(
+            ErrorKind::InvalidHeader,
+            "missing colon after header name",
+        ));
+    };
+
+    if !is_token(name) {
+        return Err(HttpError::new(
+            ErrorKind::InvalidHeaderName,
+            "header name is not a token",
+        ));
+    }
+
+    let value_start = name.len() + 1 + value.len() - value.trim_start_matches([' ', '\t']).len();
+    let value = value.trim_matches([' ', '\t']);
+
+    if let Some(index) = value
+        .bytes()
+        .position(|byte| (byte < 0x20 && byte != b'\t') || byte == 0x7f)
+    {
+        return Err(HttpError::new(
+            ErrorKind::InvalidHeaderValue,
+            "control character in header value",
+        )
+        .offset(value_start + index));
+    }
+
+    Ok((name, value))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use alloc::vec;
+
     #[test]
     fn from_str_headers_single_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Header-Name".to_string(), "Header-Value".to_string());
-        assert_eq!(
-            Headers::try_from("Header-Name: Header-Value"),
-            Ok(Headers { headers })
-        );
+        let mut headers = Headers::new();
+        headers.append("Header-Name", "Header-Value");
+        assert_eq!(Headers::try_from("Header-Name: Header-Value"), Ok(headers));
     }
 
     #[test]
     fn to_string_headers_single_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Header-Name".to_string(), "Header-Value".to_string());
-        assert_eq!(
-            Headers { headers }.to_string(),
-            "Header-Name: Header-Value".to_string()
-        );
+        let mut headers = Headers::new();
+        headers.append("Header-Name", "Header-Value");
+        assert_eq!(headers.to_string(), "Header-Name: Header-Value".to_string());
     }
 
     #[test]
     fn from_str_headers_multiple_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Header-Name1".to_string(), "Header-Value1".to_string());
-        headers.insert("Header-Name2".to_string(), "Header-Value2".to_string());
+        let mut headers = Headers::new();
+        headers.append("Header-Name1", "Header-Value1");
+        headers.append("Header-Name2", "Header-Value2");
         assert_eq!(
             Headers::try_from("Header-Name1: Header-Value1\r\nHeader-Name2: Header-Value2"),
-            Ok(Headers { headers })
+            Ok(headers)
         );
     }
 
     #[test]
     fn to_string_headers_multiple_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Header-Name1".to_string(), "Header-Value1".to_string());
-        headers.insert("Header-Name2".to_string(), "Header-Value2".to_string());
+        let mut headers = Headers::new();
+        headers.append("Header-Name1", "Header-Value1");
+        headers.append("Header-Name2", "Header-Value2");
         assert_eq!(
-            Headers { headers }.to_string(),
+            headers.to_string(),
             "Header-Name1: Header-Value1\r\nHeader-Name2: Header-Value2".to_string(),
         );
     }
@@ -104,8 +304,243 @@ mod tests {
     #[test]
     fn from_str_invalid_header_test() {
         assert_eq!(
-            Headers::try_from("Header-Name Header-Value"),
-            Err(HttpError::InvalidHeader)
+            Headers::try_from("Header-Name Header-Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn from_str_headers_ref_test() {
+        let headers_ref =
+            HeadersRef::try_from("Header-Name1: Header-Value1\r\nHeader-Name2: Header-Value2")
+                .unwrap();
+        assert_eq!(headers_ref.get("Header-Name2"), Some("Header-Value2"));
+        assert_eq!(headers_ref.get("Header-Name3"), None);
+    }
+
+    #[test]
+    fn from_str_headers_ref_empty_test() {
+        let headers_ref = HeadersRef::try_from("").unwrap();
+        assert!(headers_ref.is_empty());
+        assert_eq!(headers_ref.iter().next(), None);
+    }
+
+    #[test]
+    fn from_headers_ref_test() {
+        let mut headers = Headers::new();
+        headers.append("Header-Name1", "Header-Value1");
+        headers.append("Header-Name2", "Header-Value2");
+        assert_eq!(
+            Headers::from(
+                &HeadersRef::try_from("Header-Name1: Header-Value1\r\nHeader-Name2: Header-Value2")
+                    .unwrap()
+            ),
+            headers
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_headers_ref_test() {
+        assert_eq!(
+            HeadersRef::try_from("Header-Name Header-Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn get_case_insensitive_test() {
+        let headers = Headers::try_from("Content-Length: 4").unwrap();
+        assert_eq!(headers.get("content-length"), Some("4"));
+        assert!(headers.contains("CONTENT-LENGTH"));
+    }
+
+    #[test]
+    fn get_all_test() {
+        let headers =
+            Headers::try_from("Set-Cookie: a=1\r\nVia: proxy\r\nset-cookie: b=2").unwrap();
+        assert_eq!(headers.get("Set-Cookie"), Some("a=1"));
+        assert_eq!(
+            headers.get_all("Set-Cookie").collect::<Vec<&str>>(),
+            vec!["a=1", "b=2"]
+        );
+    }
+
+    #[test]
+    fn insert_test() {
+        let mut headers =
+            Headers::try_from("Set-Cookie: a=1\r\nVia: proxy\r\nset-cookie: b=2").unwrap();
+        headers.insert("SET-COOKIE", "c=3");
+        headers.insert("Header-Name", "Header-Value");
+        assert_eq!(
+            headers.to_string(),
+            "Set-Cookie: c=3\r\nVia: proxy\r\nHeader-Name: Header-Value".to_string()
+        );
+    }
+
+    #[test]
+    fn remove_test() {
+        let mut headers =
+            Headers::try_from("Set-Cookie: a=1\r\nVia: proxy\r\nset-cookie: b=2").unwrap();
+        assert_eq!(headers.remove("set-cookie"), Some("a=1".to_string()));
+        assert_eq!(headers.remove("set-cookie"), None);
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers.to_string(), "Via: proxy".to_string());
+    }
+
+    #[test]
+    fn to_string_keeps_order_and_case_test() {
+        let src = "via: proxy\r\nContent-Type: text/plain\r\nVIA: gateway";
+        assert_eq!(Headers::try_from(src).unwrap().to_string(), src.to_string());
+    }
+
+    #[test]
+    fn headers_ref_get_all_test() {
+        let headers_ref = HeadersRef::try_from("Via: proxy\r\nvia: gateway").unwrap();
+        assert_eq!(headers_ref.get("VIA"), Some("proxy"));
+        assert_eq!(
+            headers_ref.get_all("via").collect::<Vec<&str>>(),
+            vec!["proxy", "gateway"]
+        );
+    }
+
+    #[test]
+    fn from_str_no_whitespace_test() {
+        let mut headers = Headers::new();
+        headers.append("Host", "example.com");
+        assert_eq!(Headers::try_from("Host:example.com"), Ok(headers));
+    }
+
+    #[test]
+    fn from_str_optional_whitespace_test() {
+        let mut headers = Headers::new();
+        headers.append("Header-Name", "Header  Value");
+        assert_eq!(
+            Headers::try_from("Header-Name: \t Header  Value \t"),
+            Ok(headers)
+        );
+    }
+
+    #[test]
+    fn from_str_colon_in_value_test() {
+        let mut headers = Headers::new();
+        headers.append("Authorization", "Basic dXNlcjpwYXNz: extra");
+        headers.append("Link", "<https://example.com/page>; rel=\"next\"");
+        assert_eq!(
+            Headers::try_from(
+                "Authorization: Basic dXNlcjpwYXNz: extra\r\nLink: <https://example.com/page>; rel=\"next\""
+            ),
+            Ok(headers)
+        );
+    }
+
+    #[test]
+    fn from_str_empty_value_test() {
+        let mut headers = Headers::new();
+        headers.append("Header-Name", "");
+        assert_eq!(Headers::try_from("Header-Name:"), Ok(headers));
+    }
+
+    #[test]
+    fn from_str_invalid_header_name_test() {
+        assert_eq!(
+            Headers::try_from("Header Name: Header-Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeaderName)
+        );
+    }
+
+    #[test]
+    fn from_str_whitespace_before_colon_test() {
+        assert_eq!(
+            Headers::try_from("Header-Name : Header-Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeaderName)
+        );
+    }
+
+    #[test]
+    fn from_str_empty_header_name_test() {
+        assert_eq!(
+            Headers::try_from(": Header-Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeaderName)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_header_value_test() {
+        assert_eq!(
+            Headers::try_from("Header-Name: Header\x00Value").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidHeaderValue)
+        );
+    }
+
+    #[test]
+    fn from_str_obsolete_line_folding_test() {
+        assert_eq!(
+            Headers::try_from("Header-Name: Header\r\n Value").map_err(|error| error.kind),
+            Err(ErrorKind::ObsoleteLineFolding)
+        );
+    }
+
+    #[test]
+    fn from_str_bare_carriage_return_test() {
+        assert_eq!(
+            Headers::try_from("Header-Name: Header\rValue").map_err(|error| error.kind),
+            Err(ErrorKind::BareCarriageReturn)
         );
     }
+
+    #[test]
+    fn from_str_bare_line_feed_test() {
+        assert_eq!(
+            Headers::try_from("Header-Name1: Header-Value1\nHeader-Name2: Header-Value2")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::BareLineFeed)
+        );
+    }
+
+    #[test]
+    fn parse_headers_ref_too_many_test() {
+        let config = ParserConfig {
+            max_headers: 1,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            HeadersRef::parse(
+                "Header-Name1: Header-Value1\r\nHeader-Name2: Header-Value2",
+                &config
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_headers_ref_line_too_long_test() {
+        let config = ParserConfig {
+            max_header_line_length: 16,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            HeadersRef::parse("Header-Name: Header-Value", &config).map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn from_str_error_position_test() {
+        let error = Headers::try_from("Header-Name1: Header-Value1\r\nHeader Name2: Header-Value2")
+            .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidHeaderName);
+        assert_eq!(error.component, Some(Component::Header(1)));
+        assert_eq!(error.position, Some(29));
+    }
+
+    #[test]
+    fn from_str_invalid_header_value_position_test() {
+        let error = HeadersRef::try_from("Header-Name:  Header\x07Value").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidHeaderValue);
+        assert_eq!(error.component, Some(Component::Header(0)));
+        assert_eq!(error.position, Some(20));
+    }
 }
@@ -1,11 +1,15 @@
 #![no_std]
+#![allow(clippy::to_string_trait_impl)]
 
+pub mod chunked;
 pub mod error;
 pub mod headers;
 pub mod method;
+pub mod parser;
 pub mod request;
 pub mod response;
 pub mod router;
 pub mod status;
+pub mod target;
 pub mod uri;
 pub mod version;
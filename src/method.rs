@@ -3,7 +3,8 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
+use crate::parser;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Method {
@@ -16,6 +17,35 @@ pub enum Method {
     Connect,
     Patch,
     Trace,
+    Extension(String),
+}
+
+impl Method {
+    pub fn is_safe(&self) -> bool {
+        matches!(
+            self,
+            Method::Get | Method::Head | Method::Options | Method::Trace
+        )
+    }
+
+    pub fn is_idempotent(&self) -> bool {
+        self.is_safe() || matches!(self, Method::Put | Method::Delete)
+    }
+
+    pub fn is_cacheable(&self) -> bool {
+        matches!(self, Method::Get | Method::Head)
+    }
+
+    pub fn allows_request_body(&self) -> bool {
+        !matches!(
+            self,
+            Method::Get | Method::Head | Method::Delete | Method::Connect | Method::Trace
+        )
+    }
+
+    pub fn allows_response_body(&self) -> bool {
+        *self != Method::Head
+    }
 }
 
 impl TryFrom<&str> for Method {
@@ -32,7 +62,11 @@ impl TryFrom<&str> for Method {
             "CONNECT" => Ok(Method::Connect),
             "PATCH" => Ok(Method::Patch),
             "TRACE" => Ok(Method::Trace),
-            _ => Err(HttpError::InvalidMethod),
+            _ if parser::is_token(src) => Ok(Method::Extension(src.to_string())),
+            _ => Err(HttpError::new(
+                ErrorKind::InvalidMethod,
+                "method is not a valid token",
+            )),
         }
     }
 }
@@ -49,6 +83,7 @@ impl ToString for Method {
             Method::Connect => "CONNECT".to_string(),
             Method::Patch => "PATCH".to_string(),
             Method::Trace => "TRACE".to_string(),
+            Method::Extension(method) => method.clone(),
         }
     }
 }
@@ -147,8 +182,62 @@ mod tests {
         assert_eq!(Method::Trace.to_string(), "TRACE".to_string());
     }
 
+    #[test]
+    fn from_str_extension_test() {
+        assert_eq!(
+            Method::try_from("PROPFIND"),
+            Ok(Method::Extension("PROPFIND".to_string()))
+        );
+        assert_eq!(
+            Method::try_from("get"),
+            Ok(Method::Extension("get".to_string()))
+        );
+    }
+
+    #[test]
+    fn to_string_extension_test() {
+        assert_eq!(
+            Method::Extension("PURGE".to_string()).to_string(),
+            "PURGE".to_string()
+        );
+    }
+
     #[test]
     fn from_str_invalid_method_test() {
-        assert_eq!(Method::try_from("METHOD"), Err(HttpError::InvalidMethod));
+        assert_eq!(
+            Method::try_from("GET /").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidMethod)
+        );
+        assert_eq!(
+            Method::try_from("").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidMethod)
+        );
+    }
+
+    #[test]
+    fn properties_test() {
+        assert!(Method::Get.is_safe());
+        assert!(!Method::Post.is_safe());
+        assert!(!Method::Put.is_safe());
+        assert!(Method::Put.is_idempotent());
+        assert!(Method::Delete.is_idempotent());
+        assert!(!Method::Patch.is_idempotent());
+        assert!(Method::Head.is_cacheable());
+        assert!(!Method::Post.is_cacheable());
+        assert!(Method::Post.allows_request_body());
+        assert!(!Method::Get.allows_request_body());
+        assert!(!Method::Head.allows_response_body());
+        assert!(Method::Get.allows_response_body());
+    }
+
+    #[test]
+    fn extension_properties_test() {
+        let method = Method::Extension("MKCOL".to_string());
+
+        assert!(!method.is_safe());
+        assert!(!method.is_idempotent());
+        assert!(!method.is_cacheable());
+        assert!(method.allows_request_body());
+        assert!(method.allows_response_body());
     }
 }
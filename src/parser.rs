@@ -0,0 +1,386 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::cmp::min;
+
+use crate::chunked::{self, ChunkedDecoder};
+use crate::error::{Component, ErrorKind, HttpError};
+use crate::headers::Headers;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseStatus<T> {
+    Incomplete,
+    Complete(T, usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum BodyFraming {
+    ContentLength(usize),
+    Chunked(ChunkedDecoder),
+    UntilClose,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParserConfig {
+    pub max_uri_length: usize,
+    pub max_headers: usize,
+    pub max_header_line_length: usize,
+    pub max_body_size: usize,
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self {
+            max_uri_length: 1024,
+            max_headers: 32,
+            max_header_line_length: 1024,
+            max_body_size: 64 * 1024,
+        }
+    }
+}
+
+impl ParserConfig {
+    pub fn unlimited() -> Self {
+        Self {
+            max_uri_length: usize::MAX,
+            max_headers: usize::MAX,
+            max_header_line_length: usize::MAX,
+            max_body_size: usize::MAX,
+        }
+    }
+
+    pub(crate) fn max_start_line_length(&self) -> usize {
+        self.max_uri_length.saturating_add(32)
+    }
+
+    pub(crate) fn max_head_size(&self) -> usize {
+        self.max_headers
+            .saturating_mul(self.max_header_line_length.saturating_add(2))
+            .saturating_add(self.max_start_line_length())
+            .saturating_add(4)
+    }
+}
+
+impl Default for BodyFraming {
+    fn default() -> Self {
+        BodyFraming::ContentLength(0)
+    }
+}
+
+pub(crate) fn is_token(src: &str) -> bool {
+    !src.is_empty()
+        && src.bytes().all(|byte| {
+            byte.is_ascii_alphanumeric()
+                || matches!(
+                    byte,
+                    b'!' | b'#'
+                        | b'$'
+                        | b'%'
+                        | b'&'
+                        | b'\''
+                        | b'*'
+                        | b'+'
+                        | b'-'
+                        | b'.'
+                        | b'^'
+                        | b'_'
+                        | b'`'
+                        | b'|'
+                        | b'~'
+                )
+        })
+}
+
+pub(crate) fn check_line_breaks(src: &str) -> Result<(), HttpError> {
+    if let Some(index) = src.find('\r') {
+        Err(HttpError::new(
+            ErrorKind::BareCarriageReturn,
+            "carriage return without line feed",
+        )
+        .offset(index))
+    } else if let Some(index) = src.find('\n') {
+        Err(
+            HttpError::new(ErrorKind::BareLineFeed, "line feed without carriage return")
+                .offset(index),
+        )
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn is_chunked<'a>(values: impl Iterator<Item = &'a str>) -> bool {
+    values
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|coding| !coding.is_empty())
+        .last()
+        .map(|coding| coding.eq_ignore_ascii_case("chunked"))
+        .unwrap_or(false)
+}
+
+pub(crate) fn parse_content_length<'a>(
+    values: impl Iterator<Item = &'a str>,
+) -> Result<Option<usize>, &'static str> {
+    let mut content_length = None;
+
+    for value in values.flat_map(|value| value.split(',')).map(str::trim) {
+        if value.is_empty() || !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err("Content-Length is not a number");
+        }
+
+        let value = value
+            .parse::<usize>()
+            .map_err(|_| "Content-Length is too large")?;
+
+        if content_length.is_some() && content_length != Some(value) {
+            return Err("conflicting Content-Length values");
+        }
+
+        content_length = Some(value);
+    }
+
+    Ok(content_length)
+}
+
+pub(crate) fn parse_body(
+    framing: &mut BodyFraming,
+    src: &[u8],
+    body: &mut Vec<u8>,
+    max_body_size: usize,
+) -> Result<ParseStatus<Headers>, HttpError> {
+    match framing {
+        BodyFraming::ContentLength(remaining) => {
+            let length = min(*remaining, src.len());
+
+            body.extend_from_slice(&src[..length]);
+            *remaining -= length;
+
+            if *remaining == 0 {
+                Ok(ParseStatus::Complete(Headers::new(), length))
+            } else {
+                Ok(ParseStatus::Incomplete)
+            }
+        }
+        BodyFraming::Chunked(decoder) => decoder.decode(src, body),
+        BodyFraming::UntilClose => {
+            if src.len() > max_body_size - body.len() {
+                return Err(HttpError::new(
+                    ErrorKind::PayloadTooLarge,
+                    "body exceeds maximum size",
+                )
+                .offset(max_body_size)
+                .within(Component::Body));
+            }
+
+            body.extend_from_slice(src);
+
+            Ok(ParseStatus::Incomplete)
+        }
+    }
+}
+
+pub(crate) fn find_body_end(
+    framing: &BodyFraming,
+    src: &[u8],
+    max_body_size: usize,
+) -> Result<usize, HttpError> {
+    match framing {
+        BodyFraming::ContentLength(content_length) => {
+            if src.len() < *content_length {
+                Err(ErrorKind::Exhausted.into())
+            } else {
+                Ok(*content_length)
+            }
+        }
+        BodyFraming::Chunked(_) => {
+            chunked::find_end(src, max_body_size)?.ok_or_else(|| ErrorKind::Exhausted.into())
+        }
+        BodyFraming::UntilClose => {
+            if src.len() > max_body_size {
+                Err(
+                    HttpError::new(ErrorKind::PayloadTooLarge, "body exceeds maximum size")
+                        .offset(max_body_size)
+                        .within(Component::Body),
+                )
+            } else {
+                Ok(src.len())
+            }
+        }
+    }
+}
+
+pub(crate) fn decode_body(
+    mut framing: BodyFraming,
+    src: &[u8],
+) -> Result<(Vec<u8>, Headers), HttpError> {
+    let mut body = Vec::new();
+
+    let trailers = match parse_body(&mut framing, src, &mut body, usize::MAX)? {
+        ParseStatus::Complete(trailers, _) => trailers,
+        ParseStatus::Incomplete => Headers::new(),
+    };
+
+    Ok((body, trailers))
+}
+
+pub(crate) fn find_head_end(src: &[u8], from: usize) -> Option<usize> {
+    src[from..]
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .map(|index| from + index + 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_token_test() {
+        assert!(is_token("Content-Type"));
+        assert!(is_token("X-Custom_Header.v2!"));
+    }
+
+    #[test]
+    fn is_token_invalid_test() {
+        assert!(!is_token(""));
+        assert!(!is_token("Content Type"));
+        assert!(!is_token("Host:"));
+        assert!(!is_token("(comment)"));
+    }
+
+    #[test]
+    fn is_chunked_test() {
+        assert!(is_chunked(["gzip, Chunked"].iter().copied()));
+        assert!(is_chunked(["gzip", "chunked"].iter().copied()));
+    }
+
+    #[test]
+    fn is_chunked_not_final_test() {
+        assert!(!is_chunked(["chunked, gzip"].iter().copied()));
+        assert!(!is_chunked(["chunked", "gzip"].iter().copied()));
+        assert!(!is_chunked(core::iter::empty()));
+    }
+
+    #[test]
+    fn find_body_end_content_length_test() {
+        assert_eq!(
+            find_body_end(&BodyFraming::ContentLength(4), b"BodyNext", usize::MAX),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    fn find_body_end_chunked_test() {
+        assert_eq!(
+            find_body_end(
+                &BodyFraming::Chunked(ChunkedDecoder::new()),
+                b"4\r\nBody\r\n0\r\n\r\nNext",
+                usize::MAX
+            ),
+            Ok(14)
+        );
+    }
+
+    #[test]
+    fn find_body_end_until_close_test() {
+        assert_eq!(
+            find_body_end(&BodyFraming::UntilClose, b"Body", usize::MAX),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    fn find_body_end_exhausted_test() {
+        assert_eq!(
+            find_body_end(&BodyFraming::ContentLength(4), b"Bod", usize::MAX)
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn find_body_end_until_close_too_large_test() {
+        assert_eq!(
+            find_body_end(&BodyFraming::UntilClose, b"Body", 3).map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_body_until_close_too_large_test() {
+        let mut body = b"Bo".to_vec();
+        assert_eq!(
+            parse_body(&mut BodyFraming::UntilClose, b"dy", &mut body, 3)
+                .map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn decode_body_chunked_test() {
+        assert_eq!(
+            decode_body(
+                BodyFraming::Chunked(ChunkedDecoder::new()),
+                b"4\r\nBody\r\n0\r\n\r\n"
+            ),
+            Ok((b"Body".to_vec(), Headers::new()))
+        );
+    }
+
+    #[test]
+    fn decode_body_trailers_test() {
+        let mut trailers = Headers::new();
+        trailers.append("Checksum", "abc");
+
+        assert_eq!(
+            decode_body(
+                BodyFraming::Chunked(ChunkedDecoder::new()),
+                b"4\r\nBody\r\n0\r\nChecksum: abc\r\n\r\n"
+            ),
+            Ok((b"Body".to_vec(), trailers))
+        );
+    }
+
+    #[test]
+    fn parse_content_length_test() {
+        assert_eq!(parse_content_length(core::iter::empty()), Ok(None));
+        assert_eq!(parse_content_length(["5"].iter().copied()), Ok(Some(5)));
+        assert_eq!(
+            parse_content_length(["5", "5, 5"].iter().copied()),
+            Ok(Some(5))
+        );
+    }
+
+    #[test]
+    fn parse_content_length_invalid_test() {
+        for values in [
+            &["2", "5"][..],
+            &["2, 5"][..],
+            &["+2"][..],
+            &["-2"][..],
+            &[""][..],
+            &["99999999999999999999999"][..],
+        ] {
+            assert!(
+                parse_content_length(values.iter().copied()).is_err(),
+                "{:?}",
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn find_head_end_test() {
+        assert_eq!(find_head_end(b"GET / HTTP/1.1\r\n\r\nBody", 0), Some(18));
+    }
+
+    #[test]
+    fn find_head_end_from_test() {
+        assert_eq!(find_head_end(b"\r\n\r\nA\r\n\r\n", 1), Some(9));
+    }
+
+    #[test]
+    fn find_head_end_missing_test() {
+        assert_eq!(find_head_end(b"GET / HTTP/1.1\r\n\r", 0), None);
+    }
+}
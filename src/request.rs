@@ -1,11 +1,22 @@
 extern crate alloc;
 
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::convert::{TryFrom, TryInto};
+use core::mem;
+use core::str;
 
-use crate::error::HttpError;
-use crate::headers::Headers;
+use crate::chunked::{self, ChunkedDecoder, Chunks};
+use crate::error::{Component, ErrorKind, HttpError};
+use crate::headers::{Headers, HeadersRef};
 use crate::method::Method;
+use crate::parser::{
+    check_line_breaks, decode_body, find_body_end, find_head_end, is_chunked, parse_body,
+    parse_content_length, BodyFraming, ParseStatus, ParserConfig,
+};
+use crate::target::RequestTarget;
+use crate::uri::authority::Authority;
+use crate::uri::path::Path;
 use crate::uri::Uri;
 use crate::version::Version;
 
@@ -13,126 +24,506 @@ use crate::version::Version;
 pub struct Request {
     pub method: Method,
     pub uri: Uri,
+    pub absolute_form: bool,
     pub version: Version,
     pub headers: Headers,
-    pub body: String,
+    pub body: Vec<u8>,
+    pub trailers: Headers,
 }
 
-impl TryFrom<&str> for Request {
-    type Error = HttpError;
+impl Request {
+    pub fn parse(src: &[u8], config: &ParserConfig) -> Result<Self, HttpError> {
+        (&RequestRef::parse(src, config)?).try_into()
+    }
 
-    fn try_from(mut src: &str) -> Result<Self, Self::Error> {
-        let method = if let Some(index) = src.find(" ") {
-            let method_split = src.split_at(index);
+    pub fn body_str(&self) -> Option<&str> {
+        str::from_utf8(&self.body).ok()
+    }
 
-            src = &method_split.1[1..];
+    pub fn target(&self) -> RequestTarget {
+        match (&self.method, &self.uri) {
+            (
+                Method::Connect,
+                Uri {
+                    authority: Some(authority),
+                    ..
+                },
+            ) => RequestTarget::Authority(authority.clone()),
+            (
+                _,
+                Uri {
+                    scheme: Some(_), ..
+                },
+            ) if self.absolute_form => RequestTarget::Absolute(Uri {
+                fragment: None,
+                ..self.uri.clone()
+            }),
+            (
+                Method::Options,
+                Uri {
+                    path: None,
+                    query: None,
+                    ..
+                },
+            ) => RequestTarget::Asterisk,
+            _ => RequestTarget::Origin {
+                path: self.uri.path.clone().unwrap_or(Path {
+                    segments: Vec::new(),
+                    absolute: true,
+                }),
+                query: self.uri.query.clone(),
+            },
+        }
+    }
 
-            method_split.0.try_into()?
-        } else {
-            return Err(HttpError::Exhausted);
-        };
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = self.head_to_string().into_bytes();
+        data.extend_from_slice(&self.encoded_body());
+
+        data
+    }
+
+    fn head_to_string(&self) -> String {
+        let mut data = String::new();
 
-        let path_and_query = if let Some(index) = src.find(" ") {
-            let path_and_query_split = src.split_at(index);
+        let mut headers = self.headers.clone();
+        if let Some(authority) = &self.uri.authority {
+            let mut host_and_port = authority.host.to_string();
+
+            if let Some(port) = authority.port {
+                host_and_port += ":";
+                host_and_port += port.to_string().as_str();
+            }
+
+            headers.insert("Host", host_and_port.as_str());
+        }
+        if !self.body.is_empty() && !self.is_chunked() {
+            headers.insert("Content-Length", self.body.len().to_string().as_str());
+        }
 
-            src = &path_and_query_split.1[1..];
+        data += self.method.to_string().as_str();
+        data += " ";
+        data += self.target().to_string().as_str();
+        data += " ";
+        data += self.version.to_string().as_str();
+        if !headers.is_empty() {
+            data += "\r\n";
+            data += headers.to_string().as_str();
+        }
+        data += "\r\n\r\n";
+
+        data
+    }
 
-            path_and_query_split.0
+    fn encoded_body(&self) -> Vec<u8> {
+        if self.is_chunked() {
+            chunked::encode_with_trailers(&self.body, &self.trailers)
         } else {
-            return Err(HttpError::Exhausted);
+            self.body.clone()
+        }
+    }
+
+    fn is_chunked(&self) -> bool {
+        is_chunked(self.headers.get_all("Transfer-Encoding"))
+    }
+
+    fn try_from_head(head: &RequestRef<'_>) -> Result<Self, HttpError> {
+        let headers = Headers::from(&head.headers);
+
+        let target_start = head.method.to_string().len() + 1;
+        let target = RequestTarget::parse(head.target, &head.method)
+            .map_err(|error| error.offset(target_start).within(Component::RequestLine))?;
+        let absolute_form = matches!(target, RequestTarget::Absolute(_));
+
+        let uri = match target {
+            RequestTarget::Origin { path, query } => Uri {
+                scheme: None,
+                authority: Some(head.host()?),
+                path: Some(path),
+                query,
+                fragment: None,
+            },
+            RequestTarget::Absolute(uri) => uri,
+            RequestTarget::Authority(authority) => Uri {
+                scheme: None,
+                authority: Some(authority),
+                path: None,
+                query: None,
+                fragment: None,
+            },
+            RequestTarget::Asterisk => Uri {
+                scheme: None,
+                authority: Some(head.host()?),
+                path: None,
+                query: None,
+                fragment: None,
+            },
         };
 
-        let version = if let Some(index) = src.find("\r\n") {
-            let version_split = src.split_at(index);
+        Ok(Self {
+            method: head.method.clone(),
+            uri,
+            absolute_form,
+            version: head.version.clone(),
+            headers,
+            body: Vec::new(),
+            trailers: Headers::new(),
+        })
+    }
+}
+
+impl TryFrom<&RequestRef<'_>> for Request {
+    type Error = HttpError;
+
+    fn try_from(request_ref: &RequestRef<'_>) -> Result<Self, Self::Error> {
+        let mut request = Self::try_from_head(request_ref)?;
+
+        (request.body, request.trailers) = decode_body(
+            request_ref.body_framing(&ParserConfig::unlimited())?,
+            request_ref.raw_body,
+        )?;
+
+        Ok(request)
+    }
+}
+
+impl TryFrom<&[u8]> for Request {
+    type Error = HttpError;
+
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse(src, &ParserConfig::default())
+    }
+}
+
+impl TryFrom<&str> for Request {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        src.as_bytes().try_into()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequestRef<'a> {
+    pub method: Method,
+    pub target: &'a str,
+    pub version: Version,
+    pub headers: HeadersRef<'a>,
+    pub raw_body: &'a [u8],
+}
+
+impl<'a> RequestRef<'a> {
+    pub fn path(&self) -> &'a str {
+        match self.target.find('?') {
+            Some(index) => &self.target[..index],
+            None => self.target,
+        }
+    }
+
+    pub fn query(&self) -> Option<&'a str> {
+        self.target.find('?').map(|index| &self.target[index + 1..])
+    }
+
+    pub fn is_chunked(&self) -> bool {
+        is_chunked(self.headers.get_all("Transfer-Encoding"))
+    }
+
+    pub fn chunks(&self) -> Chunks<'a> {
+        Chunks::new(self.raw_body, self.is_chunked())
+    }
 
-            src = &version_split.1[2..];
+    pub fn to_request(&self) -> Result<Request, HttpError> {
+        self.try_into()
+    }
 
-            version_split.0.try_into()?
+    pub fn parse(src: &'a [u8], config: &ParserConfig) -> Result<Self, HttpError> {
+        let head_end = if let Some(head_end) = find_head_end(src, 0) {
+            head_end
         } else {
-            return Err(HttpError::Exhausted);
+            return Err(ErrorKind::Exhausted.into());
         };
 
-        let headers: Headers = if let Some(index) = src.find("\r\n\r\n") {
-            let header_split = src.split_at(index);
+        let head = str::from_utf8(&src[..head_end - 4]).map_err(|error| {
+            HttpError::new(ErrorKind::InvalidRequest, "head is not valid UTF-8")
+                .offset(error.valid_up_to())
+        })?;
 
-            src = &header_split.1[4..];
+        let mut request_ref = Self::try_from_head(head, config)?;
+        let body_end = head_end
+            + find_body_end(
+                &request_ref.body_framing(config)?,
+                &src[head_end..],
+                config.max_body_size,
+            )
+            .map_err(|error| error.offset(head_end).within(Component::Body))?;
 
-            header_split.0.try_into()?
+        if body_end != src.len() {
+            return Err(
+                HttpError::new(ErrorKind::InvalidRequest, "unexpected bytes after body")
+                    .offset(body_end),
+            );
+        }
+
+        request_ref.raw_body = &src[head_end..body_end];
+
+        Ok(request_ref)
+    }
+
+    fn try_from_head(src: &'a str, config: &ParserConfig) -> Result<Self, HttpError> {
+        let (request_line, headers_start) = if let Some(index) = src.find("\r\n") {
+            (&src[..index], index + 2)
         } else {
-            return Err(HttpError::Exhausted);
+            (src, src.len())
         };
 
-        let content_length = if let Some(content_length) = headers.headers.get("Content-Length") {
-            match content_length.parse::<u32>() {
-                Ok(content_length) => content_length,
-                Err(_) => return Err(HttpError::InvalidRequest),
-            }
+        check_line_breaks(request_line).map_err(|error| error.within(Component::RequestLine))?;
+
+        let (method, target_start) = if let Some(index) = request_line.find(' ') {
+            (
+                request_line[..index]
+                    .try_into()
+                    .map_err(|error: HttpError| error.within(Component::RequestLine).offset(0))?,
+                index + 1,
+            )
         } else {
-            0
+            return Err(
+                HttpError::new(ErrorKind::InvalidRequest, "missing request target")
+                    .offset(request_line.len())
+                    .within(Component::RequestLine),
+            );
         };
 
-        let body = src.to_string();
+        let (target, version) = if let Some(index) = request_line[target_start..].find(' ') {
+            let version_start = target_start + index + 1;
 
-        if (body.len() as u32) < content_length {
-            return Err(HttpError::Exhausted);
-        } else if (body.len() as u32) > content_length {
-            return Err(HttpError::InvalidRequest);
-        }
-
-        let uri = if let Some(host_header) = headers.headers.get("Host") {
-            (host_header.clone() + path_and_query).as_str().try_into()?
+            (
+                &request_line[target_start..target_start + index],
+                request_line[version_start..]
+                    .try_into()
+                    .map_err(|error: HttpError| {
+                        error.offset(version_start).within(Component::RequestLine)
+                    })?,
+            )
         } else {
-            return Err(HttpError::InvalidRequest);
+            return Err(
+                HttpError::new(ErrorKind::InvalidRequest, "missing protocol version")
+                    .offset(request_line.len())
+                    .within(Component::RequestLine),
+            );
         };
 
+        if target.len() > config.max_uri_length {
+            return Err(HttpError::new(
+                ErrorKind::UriTooLong,
+                "request target exceeds maximum length",
+            )
+            .offset(target_start + config.max_uri_length)
+            .within(Component::RequestLine));
+        }
+
         Ok(Self {
             method,
-            uri,
+            target,
             version,
-            headers,
-            body,
+            headers: HeadersRef::parse(&src[headers_start..], config)
+                .map_err(|error| error.offset(headers_start))?,
+            raw_body: &[],
         })
     }
-}
 
-impl ToString for Request {
-    fn to_string(&self) -> String {
-        let mut data = String::new();
+    fn host(&self) -> Result<Authority, HttpError> {
+        let host = self
+            .headers
+            .get("Host")
+            .ok_or_else(|| HttpError::new(ErrorKind::InvalidRequest, "missing Host header"))?;
 
-        let mut headers = self.headers.clone();
-        let mut host_and_port = self.uri.authority.host.clone();
+        Authority::try_from(host).map_err(|error| match self.headers.component("Host") {
+            Some(component) => error.within(component),
+            None => error,
+        })
+    }
+
+    fn content_length(&self) -> Result<usize, HttpError> {
+        parse_content_length(self.headers.get_all("Content-Length"))
+            .map(|content_length| content_length.unwrap_or(0))
+            .map_err(|reason| {
+                self.header_error(ErrorKind::InvalidRequest, reason, "Content-Length")
+            })
+    }
 
-        if let Some(port) = &self.uri.authority.port {
-            host_and_port += ":";
-            host_and_port += port.as_str();
+    fn body_framing(&self, config: &ParserConfig) -> Result<BodyFraming, HttpError> {
+        if self.headers.get("Transfer-Encoding").is_some() {
+            return if self.headers.get("Content-Length").is_some() {
+                Err(self.header_error(
+                    ErrorKind::InvalidRequest,
+                    "both Transfer-Encoding and Content-Length are present",
+                    "Content-Length",
+                ))
+            } else if self.is_chunked() {
+                Ok(BodyFraming::Chunked(ChunkedDecoder::with_config(*config)))
+            } else {
+                Err(self.header_error(
+                    ErrorKind::InvalidRequest,
+                    "final transfer coding is not chunked",
+                    "Transfer-Encoding",
+                ))
+            };
         }
 
-        headers.headers.insert("Host".to_string(), host_and_port);
-        if self.body.len() > 0 {
-            headers
-                .headers
-                .insert("Content-Length".to_string(), self.body.len().to_string());
+        let content_length = self.content_length()?;
+
+        if content_length > config.max_body_size {
+            return Err(self.header_error(
+                ErrorKind::PayloadTooLarge,
+                "Content-Length exceeds maximum body size",
+                "Content-Length",
+            ));
         }
 
-        data += self.method.to_string().as_str();
-        data += " ";
-        if let Some(path) = &self.uri.path {
-            data += path.to_string().as_str();
-        } else {
-            data += "/";
+        Ok(BodyFraming::ContentLength(content_length))
+    }
+
+    fn header_error(&self, kind: ErrorKind, reason: &'static str, name: &str) -> HttpError {
+        let error = HttpError::new(kind, reason);
+
+        match self.headers.component(name) {
+            Some(component) => error.within(component),
+            None => error,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RequestRef<'a> {
+    type Error = HttpError;
+
+    fn try_from(src: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse(src, &ParserConfig::default())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for RequestRef<'a> {
+    type Error = HttpError;
+
+    fn try_from(src: &'a str) -> Result<Self, Self::Error> {
+        src.as_bytes().try_into()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RequestParser {
+    config: ParserConfig,
+    buffer: Vec<u8>,
+    scanned: usize,
+    head: Option<Request>,
+    head_length: usize,
+    framing: BodyFraming,
+}
+
+impl RequestParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
         }
-        if let Some(query) = &self.uri.query {
-            data += "?";
-            data += query.to_string().as_str();
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::with_config(self.config);
+    }
+
+    pub fn parse(&mut self, src: &[u8]) -> Result<ParseStatus<Request>, HttpError> {
+        let mut consumed = 0;
+
+        let mut request = match self.head.take() {
+            Some(request) => request,
+            None => match self.parse_head(src)? {
+                ParseStatus::Complete(request, head_consumed) => {
+                    consumed = head_consumed;
+                    request
+                }
+                ParseStatus::Incomplete => return Ok(ParseStatus::Incomplete),
+            },
+        };
+
+        match parse_body(
+            &mut self.framing,
+            &src[consumed..],
+            &mut self.buffer,
+            self.config.max_body_size,
+        )
+        .map_err(|error| error.offset(self.head_length).within(Component::Body))?
+        {
+            ParseStatus::Complete(trailers, length) => {
+                consumed += length;
+                request.trailers = trailers;
+            }
+            ParseStatus::Incomplete => {
+                self.head = Some(request);
+                return Ok(ParseStatus::Incomplete);
+            }
         }
-        data += " ";
-        data += self.version.to_string().as_str();
-        data += "\r\n";
-        data += headers.to_string().as_str();
-        data += "\r\n\r\n";
-        data += self.body.as_str();
 
-        data
+        request.body = mem::take(&mut self.buffer);
+
+        Ok(ParseStatus::Complete(request, consumed))
+    }
+
+    fn parse_head(&mut self, src: &[u8]) -> Result<ParseStatus<Request>, HttpError> {
+        let buffered = self.buffer.len();
+
+        self.buffer.extend_from_slice(src);
+
+        let head_end = if let Some(head_end) = find_head_end(&self.buffer, self.scanned) {
+            head_end
+        } else {
+            let max_start_line_length = self.config.max_start_line_length();
+
+            if self.buffer.len() > max_start_line_length
+                && !self.buffer[..max_start_line_length]
+                    .windows(2)
+                    .any(|window| window == b"\r\n")
+            {
+                return Err(HttpError::new(
+                    ErrorKind::UriTooLong,
+                    "request line exceeds maximum length",
+                )
+                .offset(max_start_line_length)
+                .within(Component::RequestLine));
+            } else if self.buffer.len() > self.config.max_head_size() {
+                return Err(HttpError::new(
+                    ErrorKind::HeaderFieldsTooLarge,
+                    "head exceeds maximum size",
+                )
+                .offset(self.config.max_head_size()));
+            }
+
+            self.scanned = self.buffer.len().saturating_sub(3);
+            return Ok(ParseStatus::Incomplete);
+        };
+
+        let head = str::from_utf8(&self.buffer[..head_end - 4]).map_err(|error| {
+            HttpError::new(ErrorKind::InvalidRequest, "head is not valid UTF-8")
+                .offset(error.valid_up_to())
+        })?;
+        let head = RequestRef::try_from_head(head, &self.config)?;
+        let request = Request::try_from_head(&head)?;
+
+        self.framing = head.body_framing(&self.config)?;
+        self.head_length = head_end;
+        self.scanned = 0;
+        self.buffer.clear();
+
+        Ok(ParseStatus::Complete(request, head_end - buffered))
+    }
+}
+
+impl ToString for Request {
+    fn to_string(&self) -> String {
+        self.head_to_string() + String::from_utf8_lossy(&self.encoded_body()).as_ref()
     }
 }
 
@@ -140,35 +531,34 @@ impl ToString for Request {
 mod tests {
     use super::*;
 
-    use alloc::collections::BTreeMap;
     use alloc::vec;
 
-    use crate::uri::authority::Authority;
-    use crate::uri::path::Path;
+    use crate::uri::host::Host;
 
     #[test]
     fn from_str_full_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Content-Length".to_string(), "4".to_string());
-        headers.insert("Host".to_string(), "example.com".to_string());
+        let mut headers = Headers::new();
+        headers.append("Content-Length", "4");
+        headers.append("Host", "example.com");
 
         let method = Method::Post;
         let uri = Uri {
             scheme: None,
-            authority: Authority {
+            authority: Some(Authority {
                 username: None,
                 password: None,
-                host: "example.com".to_string(),
+                host: Host::RegName("example.com".to_string()),
                 port: None,
-            },
+            }),
             path: Some(Path {
                 segments: vec!["resource".to_string()],
+                absolute: true,
             }),
             query: None,
+            fragment: None,
         };
         let version = Version::Http11;
-        let headers = Headers { headers };
-        let body = "Body".to_string();
+        let body = b"Body".to_vec();
         assert_eq!(
             Request::try_from(
                 "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody"
@@ -176,9 +566,11 @@ mod tests {
             Ok(Request {
                 method,
                 uri,
+                absolute_form: false,
                 version,
                 headers,
                 body,
+                trailers: Headers::new(),
             })
         );
     }
@@ -188,66 +580,71 @@ mod tests {
         let method = Method::Post;
         let uri = Uri {
             scheme: None,
-            authority: Authority {
+            authority: Some(Authority {
                 username: None,
                 password: None,
-                host: "example.com".to_string(),
+                host: Host::RegName("example.com".to_string()),
                 port: None,
-            },
+            }),
             path: Some(Path {
                 segments: vec!["resource".to_string()],
+                absolute: true,
             }),
             query: None,
+            fragment: None,
         };
         let version = Version::Http11;
-        let headers = Headers {
-            headers: BTreeMap::new(),
-        };
-        let body = "Body".to_string();
+        let headers = Headers::new();
+        let body = b"Body".to_vec();
         assert_eq!(
             Request {
                 method,
                 uri,
+                absolute_form: false,
                 version,
                 headers,
                 body,
+                trailers: Headers::new(),
             }
             .to_string(),
-            "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody"
+            "POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBody"
                 .to_string()
         );
     }
 
     #[test]
     fn from_str_no_body_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Host".to_string(), "example.com".to_string());
+        let mut headers = Headers::new();
+        headers.append("Host", "example.com");
 
         let method = Method::Post;
         let uri = Uri {
             scheme: None,
-            authority: Authority {
+            authority: Some(Authority {
                 username: None,
                 password: None,
-                host: "example.com".to_string(),
+                host: Host::RegName("example.com".to_string()),
                 port: None,
-            },
+            }),
             path: Some(Path {
                 segments: vec!["resource".to_string()],
+                absolute: true,
             }),
             query: None,
+            fragment: None,
         };
         let version = Version::Http11;
-        let headers = Headers { headers };
-        let body = "".to_string();
+        let body = vec![];
         assert_eq!(
             Request::try_from("POST /resource HTTP/1.1\r\nHost: example.com\r\n\r\n"),
             Ok(Request {
                 method,
                 uri,
+                absolute_form: false,
                 version,
                 headers,
                 body,
+                trailers: Headers::new(),
             })
         );
     }
@@ -257,50 +654,71 @@ mod tests {
         let method = Method::Post;
         let uri = Uri {
             scheme: None,
-            authority: Authority {
+            authority: Some(Authority {
                 username: None,
                 password: None,
-                host: "example.com".to_string(),
+                host: Host::RegName("example.com".to_string()),
                 port: None,
-            },
+            }),
             path: Some(Path {
                 segments: vec!["resource".to_string()],
+                absolute: true,
             }),
             query: None,
+            fragment: None,
         };
         let version = Version::Http11;
-        let headers = Headers {
-            headers: BTreeMap::new(),
-        };
-        let body = "".to_string();
+        let headers = Headers::new();
+        let body = vec![];
         assert_eq!(
             Request {
                 method,
                 uri,
+                absolute_form: false,
                 version,
                 headers,
                 body,
+                trailers: Headers::new(),
             }
             .to_string(),
             "POST /resource HTTP/1.1\r\nHost: example.com\r\n\r\n".to_string()
         );
     }
 
+    #[test]
+    fn to_string_no_headers_test() {
+        assert_eq!(
+            Request {
+                method: Method::Get,
+                uri: Uri::try_from("/resource").unwrap(),
+                absolute_form: false,
+                version: Version::Http11,
+                headers: Headers::new(),
+                body: vec![],
+                trailers: Headers::new(),
+            }
+            .to_string(),
+            "GET /resource HTTP/1.1\r\n\r\n".to_string()
+        );
+    }
+
     #[test]
     fn from_str_invalid_request1_test() {
         assert_eq!(
             Request::try_from(
                 "POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBody "
-            ),
-            Err(HttpError::InvalidRequest)
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRequest)
         );
     }
 
     #[test]
     fn from_str_invalid_request2_test() {
         assert_eq!(
-            Request::try_from("POST /resource HTTP/1.1\r\nContent-Length: 4\r\n\r\nBody"),
-            Err(HttpError::InvalidRequest)
+            Request::try_from("POST /resource HTTP/1.1\r\nContent-Length: 4\r\n\r\nBody")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRequest)
         );
     }
 
@@ -309,24 +727,696 @@ mod tests {
         assert_eq!(
             Request::try_from(
                 "POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBod"
-            ),
-            Err(HttpError::Exhausted)
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
         );
     }
 
     #[test]
     fn from_str_exhausted2_test() {
         assert_eq!(
-            Request::try_from("POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4"),
-            Err(HttpError::Exhausted)
+            Request::try_from("POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
         );
     }
 
     #[test]
     fn from_str_exhausted3_test() {
         assert_eq!(
-            Request::try_from("POST /resource HTTP/1.1\r\nHost: example.com"),
-            Err(HttpError::Exhausted)
+            Request::try_from("POST /resource HTTP/1.1\r\nHost: example.com")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn from_bytes_binary_body_test() {
+        let src = b"POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\n\x00\xff\xfe\x01";
+
+        let request = Request::try_from(&src[..]).unwrap();
+        assert_eq!(request.body, vec![0x00, 0xff, 0xfe, 0x01]);
+        assert_eq!(request.body_str(), None);
+    }
+
+    #[test]
+    fn to_bytes_binary_body_test() {
+        let src = b"POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\n\x00\xff\xfe\x01";
+
+        assert_eq!(
+            Request::try_from(&src[..]).unwrap().to_bytes(),
+            src.to_vec()
+        );
+    }
+
+    #[test]
+    fn body_str_test() {
+        assert_eq!(
+            Request::try_from(
+                "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody"
+            )
+            .unwrap()
+            .body_str(),
+            Some("Body")
+        );
+    }
+
+    #[test]
+    fn from_str_chunked_test() {
+        let request = Request::try_from(
+            "POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n2;name=value\r\nBo\r\n2\r\ndy\r\n0\r\nTrailer-Name: Trailer-Value\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(request.body, b"Body".to_vec());
+        assert_eq!(request.trailers.get("Trailer-Name"), Some("Trailer-Value"));
+    }
+
+    #[test]
+    fn to_string_chunked_trailers_test() {
+        let src = "POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\nTrailer-Name: Trailer-Value\r\n\r\n";
+
+        assert_eq!(Request::try_from(src).unwrap().to_string(), src.to_string());
+    }
+
+    #[test]
+    fn to_string_chunked_test() {
+        let mut headers = Headers::new();
+        headers.append("Transfer-Encoding", "chunked");
+
+        let method = Method::Post;
+        let uri = Uri {
+            scheme: None,
+            authority: Some(Authority {
+                username: None,
+                password: None,
+                host: Host::RegName("example.com".to_string()),
+                port: None,
+            }),
+            path: Some(Path {
+                segments: vec!["resource".to_string()],
+                absolute: true,
+            }),
+            query: None,
+            fragment: None,
+        };
+        let version = Version::Http11;
+        let body = b"Body".to_vec();
+        assert_eq!(
+            Request {
+                method,
+                uri,
+                absolute_form: false,
+                version,
+                headers,
+                body,
+                trailers: Headers::new(),
+            }
+            .to_string(),
+            "POST /resource HTTP/1.1\r\nTransfer-Encoding: chunked\r\nHost: example.com\r\n\r\n4\r\nBody\r\n0\r\n\r\n"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_chunked_exhausted_test() {
+        assert_eq!(
+            Request::try_from(
+                "POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n"
+            ).map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
         );
     }
+
+    #[test]
+    fn from_str_invalid_transfer_encoding_test() {
+        assert_eq!(
+            Request::try_from(
+                "POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: gzip\r\n\r\n"
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRequest)
+        );
+    }
+
+    #[test]
+    fn from_str_repeated_transfer_encoding_test() {
+        let error = Request::try_from(
+            "POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\nTransfer-Encoding: gzip\r\n\r\n0\r\n\r\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidRequest);
+        assert_eq!(error.reason, Some("final transfer coding is not chunked"));
+    }
+
+    #[test]
+    fn from_str_transfer_encoding_and_content_length_test() {
+        let error = Request::try_from(
+            "POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 5\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidRequest);
+        assert_eq!(error.component, Some(Component::Header(1)));
+    }
+
+    #[test]
+    fn from_str_lowercase_headers_test() {
+        let request = Request::try_from(
+            "POST /resource HTTP/1.1\r\nhost: example.com\r\ncontent-length: 4\r\n\r\nBody",
+        )
+        .unwrap();
+        assert_eq!(
+            request.uri.authority.as_ref().unwrap().host,
+            Host::RegName("example.com".to_string())
+        );
+        assert_eq!(request.body, b"Body".to_vec());
+    }
+
+    #[test]
+    fn from_str_relaxed_headers_test() {
+        let request = Request::try_from(
+            "GET /resource HTTP/1.1\r\nHost:example.com\r\nAuthorization:  Bearer a:b  \r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            request.uri.authority.as_ref().unwrap().host,
+            Host::RegName("example.com".to_string())
+        );
+        assert_eq!(request.headers.get("Authorization"), Some("Bearer a:b"));
+    }
+
+    #[test]
+    fn from_str_obsolete_line_folding_test() {
+        assert_eq!(
+            Request::try_from(
+                "GET /resource HTTP/1.1\r\nHost: example.com\r\nHeader-Name: Header\r\n\tValue\r\n\r\n"
+            ).map_err(|error| error.kind),
+            Err(ErrorKind::ObsoleteLineFolding)
+        );
+    }
+
+    #[test]
+    fn from_bytes_ref_test() {
+        let src = b"POST /resource?parameter=value HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBody";
+
+        let request_ref = RequestRef::try_from(&src[..]).unwrap();
+        assert_eq!(request_ref.method, Method::Post);
+        assert_eq!(request_ref.target, "/resource?parameter=value");
+        assert_eq!(request_ref.path(), "/resource");
+        assert_eq!(request_ref.query(), Some("parameter=value"));
+        assert_eq!(request_ref.version, Version::Http11);
+        assert_eq!(request_ref.headers.get("Host"), Some("example.com"));
+        assert_eq!(request_ref.raw_body, b"Body");
+        assert_eq!(request_ref.to_request(), Request::try_from(&src[..]));
+    }
+
+    #[test]
+    fn from_bytes_ref_chunked_test() {
+        let src = b"POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n";
+
+        let request_ref = RequestRef::try_from(&src[..]).unwrap();
+        assert_eq!(request_ref.raw_body, b"4\r\nBody\r\n0\r\n\r\n");
+        assert!(request_ref.is_chunked());
+        assert_eq!(request_ref.chunks().collect::<Vec<_>>(), [&b"Body"[..]]);
+        assert_eq!(request_ref.to_request().unwrap().body, b"Body".to_vec());
+    }
+
+    #[test]
+    fn from_str_ref_no_host_test() {
+        let request_ref = RequestRef::try_from("GET / HTTP/1.0\r\n\r\n").unwrap();
+        assert_eq!(request_ref.path(), "/");
+        assert_eq!(request_ref.query(), None);
+        assert!(request_ref.headers.is_empty());
+        assert_eq!(
+            request_ref.to_request().map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRequest)
+        );
+    }
+
+    #[test]
+    fn from_str_ref_exhausted_test() {
+        assert_eq!(
+            RequestRef::try_from("POST /resource HTTP/1.1\r\nContent-Length: 4\r\n\r\nBod")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn parse_uri_too_long_test() {
+        let config = ParserConfig {
+            max_uri_length: 8,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Request::parse(
+                b"GET /resource/subresource HTTP/1.1\r\nHost: example.com\r\n\r\n",
+                &config
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::UriTooLong)
+        );
+    }
+
+    #[test]
+    fn parse_too_many_headers_test() {
+        let config = ParserConfig {
+            max_headers: 1,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Request::parse(
+                b"GET /resource HTTP/1.1\r\nHost: example.com\r\nHeader-Name: Header-Value\r\n\r\n",
+                &config
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_header_line_too_long_test() {
+        let config = ParserConfig {
+            max_header_line_length: 16,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Request::parse(
+                b"GET /resource HTTP/1.1\r\nHost: subdomain.example.com\r\n\r\n",
+                &config
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_payload_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 3,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Request::parse(
+                b"POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBody",
+                &config
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_chunked_payload_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 3,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Request::parse(
+                b"POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n",
+                &config
+            ).map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_unlimited_test() {
+        let src = b"POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\nBody";
+
+        assert_eq!(
+            Request::parse(src, &ParserConfig::unlimited()),
+            Request::try_from(&src[..])
+        );
+    }
+
+    #[test]
+    fn parser_full_test() {
+        let src = "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody";
+
+        let mut parser = RequestParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Request::try_from(src).unwrap(),
+                src.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_byte_by_byte_test() {
+        let src = "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody";
+
+        let mut parser = RequestParser::new();
+        for byte in src.as_bytes()[..src.len() - 1].chunks(1) {
+            assert_eq!(parser.parse(byte), Ok(ParseStatus::Incomplete));
+        }
+        assert_eq!(
+            parser.parse(b"y"),
+            Ok(ParseStatus::Complete(Request::try_from(src).unwrap(), 1))
+        );
+    }
+
+    #[test]
+    fn parser_split_head_end_test() {
+        let src = "GET /resource HTTP/1.1\r\nHost: example.com\r\n\r\n";
+
+        let mut parser = RequestParser::new();
+        assert_eq!(
+            parser.parse(&src.as_bytes()[..src.len() - 2]),
+            Ok(ParseStatus::Incomplete)
+        );
+        assert_eq!(
+            parser.parse(&src.as_bytes()[src.len() - 2..]),
+            Ok(ParseStatus::Complete(Request::try_from(src).unwrap(), 2))
+        );
+    }
+
+    #[test]
+    fn parser_pipelined_test() {
+        let first = "POST /resource HTTP/1.1\r\nContent-Length: 4\r\nHost: example.com\r\n\r\nBody";
+        let second = "GET /resource HTTP/1.1\r\nHost: example.com\r\n\r\n";
+        let src = first.to_string() + second;
+
+        let mut parser = RequestParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Request::try_from(first).unwrap(),
+                first.len()
+            ))
+        );
+        assert_eq!(
+            parser.parse(&src.as_bytes()[first.len()..]),
+            Ok(ParseStatus::Complete(
+                Request::try_from(second).unwrap(),
+                second.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_chunked_test() {
+        let src = "POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n";
+
+        let mut parser = RequestParser::new();
+        for byte in src.as_bytes()[..src.len() - 1].chunks(1) {
+            assert_eq!(parser.parse(byte), Ok(ParseStatus::Incomplete));
+        }
+        assert_eq!(
+            parser.parse(b"\nGET"),
+            Ok(ParseStatus::Complete(Request::try_from(src).unwrap(), 1))
+        );
+    }
+
+    #[test]
+    fn parser_chunked_trailers_test() {
+        let src = b"POST /resource HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\nChecksum: abc\r\n\r\n";
+
+        let mut parser = RequestParser::new();
+        match parser.parse(src) {
+            Ok(ParseStatus::Complete(request, consumed)) => {
+                assert_eq!(consumed, src.len());
+                assert_eq!(request.body, b"Body".to_vec());
+                assert_eq!(request.trailers.get("Checksum"), Some("abc"));
+            }
+            status => panic!("unexpected status {:?}", status),
+        }
+    }
+
+    #[test]
+    fn parser_uri_too_long_test() {
+        let config = ParserConfig {
+            max_uri_length: 8,
+            ..ParserConfig::default()
+        };
+
+        let mut parser = RequestParser::with_config(config);
+        assert_eq!(parser.parse(b"GET /resource/"), Ok(ParseStatus::Incomplete));
+        assert_eq!(
+            parser
+                .parse(b"subresource/subsubresource/subsubsubresource")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::UriTooLong)
+        );
+    }
+
+    #[test]
+    fn parser_head_too_large_test() {
+        let config = ParserConfig {
+            max_uri_length: 8,
+            max_headers: 1,
+            max_header_line_length: 16,
+            ..ParserConfig::default()
+        };
+
+        let mut parser = RequestParser::with_config(config);
+        assert_eq!(
+            parser.parse(b"GET / HTTP/1.1\r\n"),
+            Ok(ParseStatus::Incomplete)
+        );
+        for _ in 0..7 {
+            assert_eq!(parser.parse(b"Header"), Ok(ParseStatus::Incomplete));
+        }
+        assert_eq!(
+            parser.parse(b"Header").map_err(|error| error.kind),
+            Err(ErrorKind::HeaderFieldsTooLarge)
+        );
+    }
+
+    #[test]
+    fn parser_payload_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 3,
+            ..ParserConfig::default()
+        };
+
+        let mut parser = RequestParser::with_config(config);
+        assert_eq!(
+            parser
+                .parse(b"POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 4\r\n\r\n")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parser_invalid_request_test() {
+        let mut parser = RequestParser::new();
+        assert_eq!(
+            parser
+                .parse(b"POST /resource HTTP/1.1\r\nContent-Length: 4\r\n\r\n")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRequest)
+        );
+    }
+
+    #[test]
+    fn from_str_conflicting_content_length_test() {
+        let error = Request::try_from(
+            "POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 2\r\nContent-Length: 5\r\n\r\nhello",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidRequest);
+        assert_eq!(error.reason, Some("conflicting Content-Length values"));
+    }
+
+    #[test]
+    fn from_str_repeated_content_length_test() {
+        let request = Request::try_from(
+            "POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: 2\r\nContent-Length: 2\r\n\r\nhi",
+        )
+        .unwrap();
+
+        assert_eq!(request.body, b"hi");
+    }
+
+    #[test]
+    fn from_str_signed_content_length_test() {
+        let error = Request::try_from(
+            "POST / HTTP/1.1\r\nHost: example.com\r\nContent-Length: +2\r\n\r\nhi",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidRequest);
+        assert_eq!(error.component, Some(Component::Header(1)));
+    }
+
+    #[test]
+    fn from_str_request_line_bare_line_break_test() {
+        let error = Request::try_from("GET / HTTP/1.1\nHost: a\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::BareLineFeed);
+        assert_eq!(error.component, Some(Component::RequestLine));
+        assert_eq!(error.position, Some(14));
+
+        let error = Request::try_from("GET /\r HTTP/1.1\r\nHost: a\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::BareCarriageReturn);
+        assert_eq!(error.position, Some(5));
+    }
+
+    #[test]
+    fn from_str_error_position_test() {
+        let error = Request::try_from(
+            "GET /resource HTTP/1.1\r\nHost: example.com\r\nHeader-Name: Header\rValue\r\n\r\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::BareCarriageReturn);
+        assert_eq!(error.component, Some(Component::Header(1)));
+        assert_eq!(error.position, Some(62));
+        assert_eq!(
+            error.to_string(),
+            "bare carriage return in header 1 at byte 62: carriage return without line feed"
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_version_position_test() {
+        let error =
+            Request::try_from("GET /resource HTTP/x\r\nHost: example.com\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidVersion);
+        assert_eq!(error.component, Some(Component::RequestLine));
+        assert_eq!(error.position, Some(14));
+    }
+
+    #[test]
+    fn parser_chunked_error_position_test() {
+        let mut parser = RequestParser::new();
+        assert_eq!(
+            parser.parse(
+                b"POST / HTTP/1.1\r\nHost: example.com\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n"
+            ),
+            Ok(ParseStatus::Incomplete)
+        );
+
+        let error = parser.parse(b"Body!!").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidChunk);
+        assert_eq!(error.component, Some(Component::ChunkData));
+        assert_eq!(error.position, Some(73));
+    }
+
+    #[test]
+    fn from_str_percent_encoded_test() {
+        let request = Request::try_from(
+            "GET /lights/living%20room?name=a%26b HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            request.uri.path.as_ref().unwrap().segments,
+            vec!["lights".to_string(), "living room".to_string()]
+        );
+        assert_eq!(request.uri.query.as_ref().unwrap().get("name"), Some("a&b"));
+        assert_eq!(
+            request.to_string(),
+            "GET /lights/living%20room?name=a%26b HTTP/1.1\r\nHost: example.com\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn from_str_ipv6_host_test() {
+        let src = "GET /resource HTTP/1.1\r\nHost: [fe80::1]:8080\r\n\r\n";
+        let request = Request::try_from(src).unwrap();
+
+        assert_eq!(
+            request.uri.authority.as_ref().unwrap().host.as_ipv6(),
+            Some(core::net::Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))
+        );
+        assert_eq!(request.uri.authority.as_ref().unwrap().port, Some(8080));
+        assert_eq!(request.to_string(), src);
+    }
+
+    #[test]
+    fn from_str_absolute_form_test() {
+        let src = "GET http://example.com:8080/resource?parameter=value HTTP/1.1\r\nHost: example.com:8080\r\n\r\n";
+        let request = Request::try_from(src).unwrap();
+
+        assert_eq!(
+            request.uri,
+            Uri::try_from("http://example.com:8080/resource?parameter=value").unwrap()
+        );
+        assert!(request.absolute_form);
+        assert_eq!(request.to_string(), src);
+    }
+
+    #[test]
+    fn to_string_origin_form_by_default_test() {
+        let mut request = Request {
+            method: Method::Get,
+            uri: Uri::try_from("http://example.com/resource?parameter=value#top").unwrap(),
+            absolute_form: false,
+            version: Version::Http11,
+            headers: Headers::new(),
+            body: vec![],
+            trailers: Headers::new(),
+        };
+
+        assert_eq!(
+            request.to_string(),
+            "GET /resource?parameter=value HTTP/1.1\r\nHost: example.com\r\n\r\n"
+        );
+
+        request.absolute_form = true;
+        assert_eq!(
+            request.to_string(),
+            "GET http://example.com/resource?parameter=value HTTP/1.1\r\nHost: example.com\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn from_str_authority_form_test() {
+        let src = "CONNECT example.com:443 HTTP/1.1\r\nHost: example.com:443\r\n\r\n";
+        let request = Request::try_from(src).unwrap();
+
+        assert_eq!(
+            request.target(),
+            RequestTarget::Authority(Authority::try_from("example.com:443").unwrap())
+        );
+        assert_eq!(request.to_string(), src);
+    }
+
+    #[test]
+    fn from_str_asterisk_form_test() {
+        let src = "OPTIONS * HTTP/1.1\r\nHost: example.com\r\n\r\n";
+        let request = Request::try_from(src).unwrap();
+
+        assert_eq!(request.uri.path, None);
+        assert_eq!(request.target(), RequestTarget::Asterisk);
+        assert_eq!(request.to_string(), src);
+    }
+
+    #[test]
+    fn from_str_options_root_test() {
+        let src = "OPTIONS / HTTP/1.1\r\nHost: example.com\r\n\r\n";
+
+        assert_eq!(Request::try_from(src).unwrap().to_string(), src);
+    }
+
+    #[test]
+    fn from_str_invalid_target_test() {
+        let error = Request::try_from("GET * HTTP/1.1\r\nHost: example.com\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidUri);
+        assert_eq!(error.component, Some(Component::RequestLine));
+        assert_eq!(error.position, Some(4));
+    }
+
+    #[test]
+    fn from_str_invalid_host_test() {
+        let error = Request::try_from(
+            "GET /resource HTTP/1.1\r\nAccept: */*\r\nHost: exa mple.com\r\n\r\n",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidAuthority);
+        assert_eq!(error.component, Some(Component::Header(1)));
+    }
 }
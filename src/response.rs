@@ -1,12 +1,20 @@
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::convert::{TryFrom, TryInto};
+use core::mem;
+use core::str;
 
-use crate::error::HttpError;
-use crate::headers::Headers;
-use crate::status::Status;
+use crate::chunked::{self, ChunkedDecoder, Chunks};
+use crate::error::{Component, ErrorKind, HttpError};
+use crate::headers::{Headers, HeadersRef};
+use crate::method::Method;
+use crate::parser::{
+    check_line_breaks, decode_body, find_body_end, find_head_end, is_chunked, parse_body,
+    parse_content_length, BodyFraming, ParseStatus, ParserConfig,
+};
+use crate::status::{Status, StatusCode};
 use crate::version::Version;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -14,100 +22,428 @@ pub struct Response {
     pub version: Version,
     pub status: Status,
     pub headers: Headers,
-    pub body: String,
+    pub body: Vec<u8>,
+    pub trailers: Headers,
+}
+
+impl Response {
+    pub fn parse(src: &[u8], config: &ParserConfig) -> Result<Self, HttpError> {
+        (&ResponseRef::parse(src, config)?).try_into()
+    }
+
+    pub fn body_str(&self) -> Option<&str> {
+        str::from_utf8(&self.body).ok()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = self.head_to_string().into_bytes();
+        data.extend_from_slice(&self.encoded_body());
+
+        data
+    }
+
+    fn head_to_string(&self) -> String {
+        let mut data = String::new();
+        let mut headers = self.headers.clone();
+
+        if !self.body.is_empty() && !self.is_chunked() {
+            headers.insert("Content-Length", self.body.len().to_string().as_str());
+        }
+
+        data += self.version.to_string().as_str();
+        data += " ";
+        data += self.status.to_string().as_str();
+        if !headers.is_empty() {
+            data += "\r\n";
+            data += headers.to_string().as_str();
+        }
+        data += "\r\n\r\n";
+
+        data
+    }
+
+    fn encoded_body(&self) -> Vec<u8> {
+        if self.is_chunked() {
+            chunked::encode_with_trailers(&self.body, &self.trailers)
+        } else {
+            self.body.clone()
+        }
+    }
+
+    fn is_chunked(&self) -> bool {
+        is_chunked(self.headers.get_all("Transfer-Encoding"))
+    }
+
+    fn try_from_head(head: &ResponseRef<'_>) -> Result<Self, HttpError> {
+        Ok(Self {
+            version: head.version.clone(),
+            status: Status {
+                code: head.code.clone(),
+                reason: head.reason.to_string(),
+            },
+            headers: Headers::from(&head.headers),
+            body: Vec::new(),
+            trailers: Headers::new(),
+        })
+    }
+}
+
+impl TryFrom<&ResponseRef<'_>> for Response {
+    type Error = HttpError;
+
+    fn try_from(response_ref: &ResponseRef<'_>) -> Result<Self, Self::Error> {
+        let mut response = Self::try_from_head(response_ref)?;
+
+        (response.body, response.trailers) = decode_body(
+            response_ref.body_framing(&ParserConfig::unlimited(), None)?,
+            response_ref.raw_body,
+        )?;
+
+        Ok(response)
+    }
+}
+
+impl TryFrom<&[u8]> for Response {
+    type Error = HttpError;
+
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse(src, &ParserConfig::default())
+    }
 }
 
 impl TryFrom<&str> for Response {
     type Error = HttpError;
 
-    fn try_from(mut src: &str) -> Result<Self, Self::Error> {
-        let version = if let Some(index) = src.find(" ") {
-            let version_split = src.split_at(index);
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        src.as_bytes().try_into()
+    }
+}
 
-            src = &version_split.1[1..];
+impl From<&HttpError> for Response {
+    fn from(error: &HttpError) -> Self {
+        let mut headers = Headers::new();
+        headers.append("Content-Type", "text/plain");
+        headers.append("Connection", "close");
 
-            version_split.0.try_into()?
-        } else {
-            return Err(HttpError::Exhausted);
-        };
+        Self {
+            version: Version::Http11,
+            status: StatusCode::from(error.kind).into(),
+            headers,
+            body: error.to_string().into_bytes(),
+            trailers: Headers::new(),
+        }
+    }
+}
 
-        let skip_headers = src.find("\r\n") == src.find("\r\n\r\n");
+impl From<HttpError> for Response {
+    fn from(error: HttpError) -> Self {
+        Self::from(&error)
+    }
+}
 
-        let status = if let Some(index) = src.find("\r\n") {
-            let status_split = src.split_at(index);
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseRef<'a> {
+    pub version: Version,
+    pub code: StatusCode,
+    pub reason: &'a str,
+    pub headers: HeadersRef<'a>,
+    pub raw_body: &'a [u8],
+}
+
+impl<'a> ResponseRef<'a> {
+    pub fn is_chunked(&self) -> bool {
+        is_chunked(self.headers.get_all("Transfer-Encoding"))
+    }
 
-            src = &status_split.1[2..];
+    pub fn chunks(&self) -> Chunks<'a> {
+        Chunks::new(self.raw_body, self.is_chunked())
+    }
+
+    pub fn to_response(&self) -> Result<Response, HttpError> {
+        self.try_into()
+    }
 
-            status_split.0.try_into()?
+    pub fn parse(src: &'a [u8], config: &ParserConfig) -> Result<Self, HttpError> {
+        let head_end = if let Some(head_end) = find_head_end(src, 0) {
+            head_end
         } else {
-            return Err(HttpError::Exhausted);
+            return Err(ErrorKind::Exhausted.into());
         };
 
-        let headers: Headers = if skip_headers {
-            src = &src[2..];
-            Headers {
-                headers: BTreeMap::new(),
-            }
-        } else {
-            if let Some(index) = src.find("\r\n\r\n") {
-                let header_split = src.split_at(index);
+        let head = str::from_utf8(&src[..head_end - 4]).map_err(|error| {
+            HttpError::new(ErrorKind::InvalidResponse, "head is not valid UTF-8")
+                .offset(error.valid_up_to())
+        })?;
 
-                src = &header_split.1[4..];
+        let mut response_ref = Self::try_from_head(head, config)?;
+        let body_end = head_end
+            + find_body_end(
+                &response_ref.body_framing(config, None)?,
+                &src[head_end..],
+                config.max_body_size,
+            )
+            .map_err(|error| error.offset(head_end).within(Component::Body))?;
 
-                header_split.0.try_into()?
-            } else {
-                return Err(HttpError::Exhausted);
-            }
+        if body_end != src.len() {
+            return Err(
+                HttpError::new(ErrorKind::InvalidResponse, "unexpected bytes after body")
+                    .offset(body_end),
+            );
+        }
+
+        response_ref.raw_body = &src[head_end..body_end];
+
+        Ok(response_ref)
+    }
+
+    fn try_from_head(src: &'a str, config: &ParserConfig) -> Result<Self, HttpError> {
+        let (status_line, headers_start) = if let Some(index) = src.find("\r\n") {
+            (&src[..index], index + 2)
+        } else {
+            (src, src.len())
         };
 
-        let content_length = if let Some(content_length) = headers.headers.get("Content-Length") {
-            match content_length.parse::<u32>() {
-                Ok(content_length) => content_length,
-                Err(_) => return Err(HttpError::InvalidResponse),
-            }
+        check_line_breaks(status_line).map_err(|error| error.within(Component::StatusLine))?;
+
+        let (version, code_start) = if let Some(index) = status_line.find(' ') {
+            (
+                status_line[..index]
+                    .try_into()
+                    .map_err(|error: HttpError| error.within(Component::StatusLine).offset(0))?,
+                index + 1,
+            )
         } else {
-            0
+            return Err(
+                HttpError::new(ErrorKind::InvalidResponse, "missing status code")
+                    .offset(status_line.len())
+                    .within(Component::StatusLine),
+            );
         };
 
-        let body = src.to_string();
+        let (code_src, reason) = if let Some(index) = status_line[code_start..].find(' ') {
+            (
+                &status_line[code_start..code_start + index],
+                &status_line[code_start + index + 1..],
+            )
+        } else {
+            (&status_line[code_start..], "")
+        };
 
-        if (body.len() as u32) < content_length {
-            return Err(HttpError::Exhausted);
-        } else if (body.len() as u32) > content_length {
-            return Err(HttpError::InvalidResponse);
-        }
+        let code = code_src
+            .try_into()
+            .map_err(|error: HttpError| error.offset(code_start).within(Component::StatusLine))?;
 
         Ok(Self {
             version,
-            status,
-            headers,
-            body,
+            code,
+            reason,
+            headers: HeadersRef::parse(&src[headers_start..], config)
+                .map_err(|error| error.offset(headers_start))?,
+            raw_body: &[],
+        })
+    }
+
+    fn content_length(&self) -> Result<Option<usize>, HttpError> {
+        parse_content_length(self.headers.get_all("Content-Length")).map_err(|reason| {
+            self.header_error(ErrorKind::InvalidResponse, reason, "Content-Length")
+        })
+    }
+
+    fn header_error(&self, kind: ErrorKind, reason: &'static str, name: &str) -> HttpError {
+        let error = HttpError::new(kind, reason);
+
+        match self.headers.component(name) {
+            Some(component) => error.within(component),
+            None => error,
+        }
+    }
+
+    fn body_framing(
+        &self,
+        config: &ParserConfig,
+        request_method: Option<&Method>,
+    ) -> Result<BodyFraming, HttpError> {
+        let is_tunnel = request_method == Some(&Method::Connect) && self.code.is_success();
+
+        if request_method == Some(&Method::Head) || is_tunnel {
+            return Ok(BodyFraming::ContentLength(0));
+        }
+
+        if self.code.is_informational()
+            || matches!(self.code, StatusCode::NoContent | StatusCode::NotModified)
+        {
+            return Ok(BodyFraming::ContentLength(0));
+        }
+
+        if self.headers.get("Transfer-Encoding").is_some() {
+            return Ok(if self.is_chunked() {
+                BodyFraming::Chunked(ChunkedDecoder::with_config(*config))
+            } else {
+                BodyFraming::UntilClose
+            });
+        }
+
+        Ok(match self.content_length()? {
+            Some(content_length) if content_length > config.max_body_size => {
+                return Err(self.header_error(
+                    ErrorKind::PayloadTooLarge,
+                    "Content-Length exceeds maximum body size",
+                    "Content-Length",
+                ))
+            }
+            Some(content_length) => BodyFraming::ContentLength(content_length),
+            None => BodyFraming::UntilClose,
         })
     }
 }
 
-impl ToString for Response {
-    fn to_string(&self) -> String {
-        let mut data = String::new();
-        let mut headers = self.headers.clone();
+impl<'a> TryFrom<&'a [u8]> for ResponseRef<'a> {
+    type Error = HttpError;
+
+    fn try_from(src: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse(src, &ParserConfig::default())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ResponseRef<'a> {
+    type Error = HttpError;
+
+    fn try_from(src: &'a str) -> Result<Self, Self::Error> {
+        src.as_bytes().try_into()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ResponseParser {
+    config: ParserConfig,
+    buffer: Vec<u8>,
+    scanned: usize,
+    head: Option<Response>,
+    head_length: usize,
+    framing: BodyFraming,
+    request_method: Option<Method>,
+}
+
+impl ResponseParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        if self.body.len() > 0 {
-            headers
-                .headers
-                .insert("Content-Length".to_string(), self.body.len().to_string());
+    pub fn with_config(config: ParserConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
         }
+    }
 
-        data += self.version.to_string().as_str();
-        data += " ";
-        data += self.status.to_string().as_str();
-        if headers.headers.len() > 0 {
-            data += "\r\n";
-            data += headers.to_string().as_str();
+    pub fn reset(&mut self) {
+        *self = Self::with_config(self.config);
+    }
+
+    pub fn set_request_method(&mut self, method: Method) {
+        self.request_method = Some(method);
+    }
+
+    pub fn head(&self) -> Option<&Response> {
+        self.head.as_ref()
+    }
+
+    pub fn parse(&mut self, src: &[u8]) -> Result<ParseStatus<Response>, HttpError> {
+        let mut consumed = 0;
+
+        let mut response = match self.head.take() {
+            Some(response) => response,
+            None => match self.parse_head(src)? {
+                ParseStatus::Complete(response, head_consumed) => {
+                    consumed = head_consumed;
+                    response
+                }
+                ParseStatus::Incomplete => return Ok(ParseStatus::Incomplete),
+            },
+        };
+
+        match parse_body(
+            &mut self.framing,
+            &src[consumed..],
+            &mut self.buffer,
+            self.config.max_body_size,
+        )
+        .map_err(|error| error.offset(self.head_length).within(Component::Body))?
+        {
+            ParseStatus::Complete(trailers, length) => {
+                consumed += length;
+                response.trailers = trailers;
+            }
+            ParseStatus::Incomplete => {
+                self.head = Some(response);
+                return Ok(ParseStatus::Incomplete);
+            }
         }
-        data += "\r\n\r\n";
-        data += self.body.as_str();
 
-        data
+        response.body = self.take_body();
+        if !response.status.code.is_informational() {
+            self.request_method = None;
+        }
+
+        Ok(ParseStatus::Complete(response, consumed))
+    }
+
+    pub fn finish(&mut self) -> Result<Response, HttpError> {
+        match (self.head.take(), &self.framing) {
+            (Some(mut response), BodyFraming::UntilClose) => {
+                response.body = self.take_body();
+
+                Ok(response)
+            }
+            _ => Err(ErrorKind::Exhausted.into()),
+        }
+    }
+
+    fn parse_head(&mut self, src: &[u8]) -> Result<ParseStatus<Response>, HttpError> {
+        let buffered = self.buffer.len();
+
+        self.buffer.extend_from_slice(src);
+
+        let head_end = if let Some(head_end) = find_head_end(&self.buffer, self.scanned) {
+            head_end
+        } else {
+            if self.buffer.len() > self.config.max_head_size() {
+                return Err(HttpError::new(
+                    ErrorKind::HeaderFieldsTooLarge,
+                    "head exceeds maximum size",
+                )
+                .offset(self.config.max_head_size()));
+            }
+
+            self.scanned = self.buffer.len().saturating_sub(3);
+            return Ok(ParseStatus::Incomplete);
+        };
+
+        let head = str::from_utf8(&self.buffer[..head_end - 4]).map_err(|error| {
+            HttpError::new(ErrorKind::InvalidResponse, "head is not valid UTF-8")
+                .offset(error.valid_up_to())
+        })?;
+        let head = ResponseRef::try_from_head(head, &self.config)?;
+        let response = Response::try_from_head(&head)?;
+
+        self.framing = head.body_framing(&self.config, self.request_method.as_ref())?;
+        self.head_length = head_end;
+        self.scanned = 0;
+        self.buffer.clear();
+
+        Ok(ParseStatus::Complete(response, head_end - buffered))
+    }
+
+    fn take_body(&mut self) -> Vec<u8> {
+        self.framing = BodyFraming::default();
+
+        mem::take(&mut self.buffer)
+    }
+}
+
+impl ToString for Response {
+    fn to_string(&self) -> String {
+        self.head_to_string() + String::from_utf8_lossy(&self.encoded_body()).as_ref()
     }
 }
 
@@ -115,26 +451,26 @@ impl ToString for Response {
 mod tests {
     use super::*;
 
-    use alloc::collections::BTreeMap;
+    use alloc::vec;
 
-    use crate::status::StatusCode;
+    use crate::request::Request;
 
     #[test]
     fn from_str_full_test() {
-        let mut headers = BTreeMap::new();
-        headers.insert("Content-Length".to_string(), "4".to_string());
+        let mut headers = Headers::new();
+        headers.append("Content-Length", "4");
 
         let version = Version::Http11;
         let status = Status::from(StatusCode::Ok);
-        let headers = Headers { headers };
-        let body = "Body".to_string();
+        let body = b"Body".to_vec();
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\nContent-Length: 4\r\n\r\nBody"),
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody"),
             Ok(Response {
                 version,
                 status,
                 headers,
                 body,
+                trailers: Headers::new(),
             }),
         );
     }
@@ -143,19 +479,18 @@ mod tests {
     fn to_string_full_test() {
         let version = Version::Http11;
         let status = Status::from(StatusCode::Ok);
-        let headers = Headers {
-            headers: BTreeMap::new(),
-        };
-        let body = "Body".to_string();
+        let headers = Headers::new();
+        let body = b"Body".to_vec();
         assert_eq!(
             Response {
                 version,
                 status,
                 headers,
                 body,
+                trailers: Headers::new(),
             }
             .to_string(),
-            "HTTP/1.1 200 Ok\r\nContent-Length: 4\r\n\r\nBody".to_string()
+            "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody".to_string()
         );
     }
 
@@ -163,17 +498,16 @@ mod tests {
     fn from_str_no_body_test() {
         let version = Version::Http11;
         let status = Status::from(StatusCode::Ok);
-        let headers = Headers {
-            headers: BTreeMap::new(),
-        };
-        let body = "".to_string();
+        let headers = Headers::new();
+        let body = vec![];
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\n\r\n"),
+            Response::try_from("HTTP/1.1 200 OK\r\n\r\n"),
             Ok(Response {
                 version,
                 status,
                 headers,
                 body,
+                trailers: Headers::new(),
             }),
         );
     }
@@ -182,51 +516,550 @@ mod tests {
     fn to_string_no_body_test() {
         let version = Version::Http11;
         let status = Status::from(StatusCode::Ok);
-        let headers = Headers {
-            headers: BTreeMap::new(),
-        };
-        let body = "".to_string();
+        let headers = Headers::new();
+        let body = vec![];
         assert_eq!(
             Response {
                 version,
                 status,
                 headers,
                 body,
+                trailers: Headers::new(),
             }
             .to_string(),
-            "HTTP/1.1 200 Ok\r\n\r\n".to_string(),
+            "HTTP/1.1 200 OK\r\n\r\n".to_string(),
         );
     }
 
     #[test]
     fn from_str_invalid_request1_test() {
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\nContent-Length: 4\r\n\r\nBody "),
-            Err(HttpError::InvalidResponse)
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody ")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidResponse)
         );
     }
 
     #[test]
     fn from_str_exhausted1_test() {
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\nContent-Length: 4\r\n\r\nBod"),
-            Err(HttpError::Exhausted)
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBod")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
         );
     }
 
     #[test]
     fn from_str_exhausted2_test() {
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\nContent-Length: 4"),
-            Err(HttpError::Exhausted)
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4").map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
         );
     }
 
     #[test]
     fn from_str_exhausted3_test() {
         assert_eq!(
-            Response::try_from("HTTP/1.1 200 Ok\r\n"),
-            Err(HttpError::Exhausted)
+            Response::try_from("HTTP/1.1 200 OK\r\n").map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn from_bytes_binary_body_test() {
+        let src = b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n\x00\xff\xfe\x01";
+
+        let response = Response::try_from(&src[..]).unwrap();
+        assert_eq!(response.body, vec![0x00, 0xff, 0xfe, 0x01]);
+        assert_eq!(response.body_str(), None);
+    }
+
+    #[test]
+    fn to_bytes_binary_body_test() {
+        let src = b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n\x00\xff\xfe\x01";
+
+        assert_eq!(
+            Response::try_from(&src[..]).unwrap().to_bytes(),
+            src.to_vec()
+        );
+    }
+
+    #[test]
+    fn body_str_test() {
+        assert_eq!(
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody")
+                .unwrap()
+                .body_str(),
+            Some("Body")
+        );
+    }
+
+    #[test]
+    fn from_str_chunked_test() {
+        let mut headers = Headers::new();
+        headers.append("Transfer-Encoding", "chunked");
+
+        assert_eq!(
+            Response::try_from(
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n"
+            ),
+            Ok(Response {
+                version: Version::Http11,
+                status: Status::from(StatusCode::Ok),
+                headers,
+                body: b"Body".to_vec(),
+                trailers: Headers::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_repeated_transfer_encoding_test() {
+        let response = Response::try_from(
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: gzip\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n",
+        )
+        .unwrap();
+
+        assert_eq!(response.body, b"Body");
+        assert!(response.is_chunked());
+    }
+
+    #[test]
+    fn to_string_chunked_test() {
+        let mut headers = Headers::new();
+        headers.append("Transfer-Encoding", "chunked");
+
+        assert_eq!(
+            Response {
+                version: Version::Http11,
+                status: Status::from(StatusCode::Ok),
+                headers,
+                body: b"Body".to_vec(),
+                trailers: Headers::new(),
+            }
+            .to_string(),
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\n"
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_chunked_test() {
+        assert_eq!(
+            Response::try_from(
+                "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nBody\r\n0\r\n\r\nExtra"
+            )
+            .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidResponse)
+        );
+    }
+
+    #[test]
+    fn from_bytes_ref_test() {
+        let src = b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody";
+
+        let response_ref = ResponseRef::try_from(&src[..]).unwrap();
+        assert_eq!(response_ref.version, Version::Http11);
+        assert_eq!(response_ref.code, StatusCode::Ok);
+        assert_eq!(response_ref.reason, "OK");
+        assert_eq!(response_ref.headers.get("Content-Length"), Some("4"));
+        assert_eq!(response_ref.raw_body, b"Body");
+        assert_eq!(response_ref.to_response(), Response::try_from(&src[..]));
+    }
+
+    #[test]
+    fn from_bytes_ref_empty_reason_test() {
+        let response_ref = ResponseRef::try_from(&b"HTTP/1.1 204\r\n\r\n"[..]).unwrap();
+        assert_eq!(response_ref.code, StatusCode::NoContent);
+        assert_eq!(response_ref.reason, "");
+
+        let response_ref = ResponseRef::try_from(&b"HTTP/1.1 499 \r\n\r\n"[..]).unwrap();
+        assert_eq!(response_ref.code, StatusCode::Other(499));
+        assert_eq!(response_ref.reason, "");
+    }
+
+    #[test]
+    fn from_str_invalid_status_digits_test() {
+        for src in ["HTTP/1.1 20 OK\r\n\r\n", "HTTP/1.1 2000\r\n\r\n"] {
+            let error = Response::try_from(src).unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::InvalidStatus);
+            assert_eq!(error.position, Some(9));
+        }
+    }
+
+    #[test]
+    fn from_bytes_ref_until_close_test() {
+        let src = b"HTTP/1.1 200 OK\r\n\r\nBody";
+
+        let response_ref = ResponseRef::try_from(&src[..]).unwrap();
+        assert_eq!(response_ref.raw_body, b"Body");
+    }
+
+    #[test]
+    fn from_str_ref_exhausted_test() {
+        assert_eq!(
+            ResponseRef::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4")
+                .map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn parser_content_length_test() {
+        let src = "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody";
+
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response::try_from(src).unwrap(),
+                src.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_head_before_body_test() {
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBo"),
+            Ok(ParseStatus::Incomplete)
+        );
+        assert_eq!(
+            parser.head().map(|response| &response.status),
+            Some(&Status::from(StatusCode::Ok))
+        );
+        assert_eq!(
+            parser.parse(b"dyHTTP/1.1"),
+            Ok(ParseStatus::Complete(
+                Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody").unwrap(),
+                2
+            ))
+        );
+        assert_eq!(parser.head(), None);
+    }
+
+    #[test]
+    fn parser_chunked_test() {
+        let mut headers = Headers::new();
+        headers.append("Transfer-Encoding", "chunked");
+
+        let src =
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nBo\r\n2\r\ndy\r\n0\r\n\r\n";
+
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response {
+                    version: Version::Http11,
+                    status: Status::from(StatusCode::Ok),
+                    headers,
+                    body: b"Body".to_vec(),
+                    trailers: Headers::new(),
+                },
+                src.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_until_close_test() {
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(b"HTTP/1.1 200 OK\r\nServer: ross\r\n\r\nBo"),
+            Ok(ParseStatus::Incomplete)
+        );
+        assert_eq!(parser.parse(b"dy"), Ok(ParseStatus::Incomplete));
+
+        let mut headers = Headers::new();
+        headers.append("Server", "ross");
+        assert_eq!(
+            parser.finish(),
+            Ok(Response {
+                version: Version::Http11,
+                status: Status::from(StatusCode::Ok),
+                headers,
+                body: b"Body".to_vec(),
+                trailers: Headers::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn parser_no_content_test() {
+        let src = "HTTP/1.1 204 No Content\r\n\r\n";
+
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response::try_from(src).unwrap(),
+                src.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_head_request_test() {
+        let src = "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n";
+
+        let mut parser = ResponseParser::new();
+        parser.set_request_method(Method::Head);
+
+        let mut headers = Headers::new();
+        headers.append("Content-Length", "4");
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response {
+                    version: Version::Http11,
+                    status: Status::from(StatusCode::Ok),
+                    headers,
+                    body: vec![],
+                    trailers: Headers::new(),
+                },
+                src.len()
+            ))
+        );
+        assert_eq!(parser.parse(src.as_bytes()), Ok(ParseStatus::Incomplete));
+    }
+
+    #[test]
+    fn parser_connect_request_test() {
+        let src = "HTTP/1.1 200 Connection Established\r\n\r\n";
+
+        let mut parser = ResponseParser::new();
+        parser.set_request_method(Method::Connect);
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response::try_from(src).unwrap(),
+                src.len()
+            ))
+        );
+
+        let src = "HTTP/1.1 407 Proxy Authentication Required\r\nContent-Length: 4\r\n\r\nBody";
+
+        parser.set_request_method(Method::Connect);
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response::try_from(src).unwrap(),
+                src.len()
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_early_hints_test() {
+        let src = "HTTP/1.1 103 Early Hints\r\nLink: </style.css>; rel=preload\r\n\r\nHTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nhi";
+        let hints_length = src.find("HTTP/1.1 200").unwrap();
+
+        let mut parser = ResponseParser::new();
+        parser.set_request_method(Method::Head);
+
+        let hints = match parser.parse(src.as_bytes()) {
+            Ok(ParseStatus::Complete(response, consumed)) => {
+                assert_eq!(consumed, hints_length);
+                response
+            }
+            status => panic!("unexpected status: {:?}", status),
+        };
+        assert_eq!(hints.status.code, StatusCode::EarlyHints);
+        assert_eq!(hints.headers.get("Link"), Some("</style.css>; rel=preload"));
+
+        let mut headers = Headers::new();
+        headers.append("Content-Length", "2");
+        assert_eq!(
+            parser.parse(&src.as_bytes()[hints_length..]),
+            Ok(ParseStatus::Complete(
+                Response {
+                    version: Version::Http11,
+                    status: Status::from(StatusCode::Ok),
+                    headers,
+                    body: vec![],
+                    trailers: Headers::new(),
+                },
+                src.len() - hints_length - 2
+            ))
+        );
+
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(src.as_bytes()),
+            Ok(ParseStatus::Complete(
+                Response::try_from(&src[..hints_length]).unwrap(),
+                hints_length
+            ))
+        );
+        assert_eq!(
+            parser.parse(&src.as_bytes()[hints_length..]),
+            Ok(ParseStatus::Complete(
+                Response::try_from(&src[hints_length..]).unwrap(),
+                src.len() - hints_length
+            ))
+        );
+    }
+
+    #[test]
+    fn parser_until_close_payload_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 3,
+            ..ParserConfig::default()
+        };
+
+        let mut parser = ResponseParser::with_config(config);
+        assert_eq!(
+            parser.parse(b"HTTP/1.1 200 OK\r\n\r\nBo"),
+            Ok(ParseStatus::Incomplete)
+        );
+        assert_eq!(
+            parser.parse(b"dy").map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parse_payload_too_large_test() {
+        let config = ParserConfig {
+            max_body_size: 3,
+            ..ParserConfig::default()
+        };
+        assert_eq!(
+            Response::parse(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBody", &config)
+                .map_err(|error| error.kind),
+            Err(ErrorKind::PayloadTooLarge)
+        );
+    }
+
+    #[test]
+    fn parser_finish_exhausted_test() {
+        let mut parser = ResponseParser::new();
+        assert_eq!(
+            parser.parse(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\nBo"),
+            Ok(ParseStatus::Incomplete)
+        );
+        assert_eq!(
+            parser.finish().map_err(|error| error.kind),
+            Err(ErrorKind::Exhausted)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_status_position_test() {
+        let error = Response::try_from("HTTP/1.1 2x0 OK\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidStatus);
+        assert_eq!(error.component, Some(Component::StatusLine));
+        assert_eq!(error.position, Some(9));
+    }
+
+    #[test]
+    fn from_str_status_line_bare_line_break_test() {
+        let error = Response::try_from("HTTP/1.1 200 OK\nServer: ross\r\n\r\n").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::BareLineFeed);
+        assert_eq!(error.component, Some(Component::StatusLine));
+        assert_eq!(error.position, Some(15));
+    }
+
+    #[test]
+    fn from_str_content_length_error_test() {
+        let error =
+            Response::try_from("HTTP/1.1 200 OK\r\nServer: ross\r\nContent-Length: x\r\n\r\n")
+                .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidResponse);
+        assert_eq!(error.component, Some(Component::Header(1)));
+    }
+
+    #[test]
+    fn from_str_conflicting_content_length_test() {
+        let error = Response::try_from(
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nContent-Length: 5\r\n\r\nhello",
+        )
+        .unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidResponse);
+        assert_eq!(error.reason, Some("conflicting Content-Length values"));
+    }
+
+    #[test]
+    fn from_str_signed_content_length_test() {
+        let error =
+            Response::try_from("HTTP/1.1 200 OK\r\nContent-Length: +2\r\n\r\nhi").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidResponse);
+        assert_eq!(error.component, Some(Component::Header(0)));
+    }
+
+    #[test]
+    fn from_error_test() {
+        let error =
+            Request::try_from("GET /resource HTTP/1.1\r\nHost example.com\r\n\r\n").unwrap_err();
+
+        let mut headers = Headers::new();
+        headers.append("Content-Type", "text/plain");
+        headers.append("Connection", "close");
+
+        assert_eq!(
+            Response::from(error),
+            Response {
+                version: Version::Http11,
+                status: StatusCode::BadRequest.into(),
+                headers,
+                body: b"invalid header in header 0 at byte 24: missing colon after header name"
+                    .to_vec(),
+                trailers: Headers::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn from_error_status_test() {
+        let status = |src: &str| {
+            Response::from(Request::try_from(src).unwrap_err())
+                .status
+                .code
+        };
+
+        assert_eq!(
+            status("GE@T /resource HTTP/1.1\r\nHost: example.com\r\n\r\n"),
+            StatusCode::BadRequest
+        );
+        assert_eq!(
+            status("GET /resource HTTP/x\r\nHost: example.com\r\n\r\n"),
+            StatusCode::BadRequest
+        );
+        assert_eq!(
+            status("GET /resource HTTP/4.0\r\nHost: example.com\r\n\r\n"),
+            StatusCode::HttpVersionNotSupported
+        );
+        assert_eq!(
+            status(
+                "POST /resource HTTP/1.1\r\nHost: example.com\r\nContent-Length: 1000000\r\n\r\n"
+            ),
+            StatusCode::PayloadTooLarge
+        );
+    }
+
+    #[test]
+    fn from_error_uri_too_long_test() {
+        let config = ParserConfig {
+            max_uri_length: 8,
+            ..ParserConfig::default()
+        };
+        let error = Request::parse(
+            b"GET /resource/subresource HTTP/1.1\r\nHost: example.com\r\n\r\n",
+            &config,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            Response::from(error).to_string(),
+            "HTTP/1.1 414 URI Too Long\r\nContent-Type: text/plain\r\nConnection: close\r\nContent-Length: 78\r\n\r\nuri too long in request line at byte 12: request target exceeds maximum length"
         );
     }
 }
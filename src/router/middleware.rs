@@ -0,0 +1,19 @@
+use crate::request::Request;
+use crate::response::Response;
+
+/// Global layers run around routing, so they see every request and may rewrite
+/// its target. Group layers whose prefix matches the request path run around
+/// the handler of the matched route, followed by its route layers, and also
+/// around the automatic `OPTIONS` and 405 responses, so they can answer CORS
+/// preflight requests. `before` runs in registration order and `after` in
+/// reverse; returning a response from `before` skips the remaining layers and
+/// the handler, and only the layers already entered see it in `after`.
+pub trait Middleware {
+    fn before(&mut self, request: Request) -> Result<Request, Response> {
+        Ok(request)
+    }
+
+    fn after(&mut self, response: Response) -> Response {
+        response
+    }
+}
@@ -0,0 +1,819 @@
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::mem;
+use core::ops::Range;
+
+use crate::error::{ErrorKind, HttpError};
+use crate::headers::Headers;
+use crate::method::Method;
+use crate::parser::is_chunked;
+use crate::request::Request;
+use crate::response::Response;
+use crate::status::{Status, StatusCode};
+use crate::version::Version;
+
+pub mod middleware;
+pub mod params;
+pub mod pattern;
+mod tree;
+
+use middleware::Middleware;
+use params::Params;
+use pattern::Pattern;
+use tree::Node;
+
+type RequestHandler<'a> = Box<dyn FnMut(Request, Params) -> Result<Response, HttpError> + 'a>;
+type Layer<'a> = Box<dyn Middleware + 'a>;
+
+#[derive(Debug, Clone)]
+pub struct HttpRoute {
+    pub method: Method,
+    pub pattern: Pattern,
+}
+
+pub struct HttpRouter<'a> {
+    routes: Vec<(HttpRoute, RequestHandler<'a>, Vec<Layer<'a>>)>,
+    tree: Node,
+    layers: Vec<Layer<'a>>,
+    groups: Vec<(Pattern, Layer<'a>)>,
+    not_found_handler: RequestHandler<'a>,
+    captures: Vec<Range<usize>>,
+}
+
+impl<'a> HttpRouter<'a> {
+    pub fn new(not_found_handler: RequestHandler<'a>) -> Self {
+        Self {
+            routes: vec![],
+            tree: Node::default(),
+            layers: vec![],
+            groups: vec![],
+            not_found_handler,
+            captures: vec![],
+        }
+    }
+
+    pub fn add_route(
+        &mut self,
+        method: Method,
+        pattern: &str,
+        handler: RequestHandler<'a>,
+    ) -> Result<(), HttpError> {
+        let pattern = Pattern::try_from(pattern)?;
+
+        self.tree
+            .insert(&pattern.segments, method.clone(), self.routes.len())?;
+
+        self.routes
+            .push((HttpRoute { method, pattern }, handler, vec![]));
+
+        Ok(())
+    }
+
+    pub fn add_layer(&mut self, layer: Layer<'a>) {
+        self.layers.push(layer);
+    }
+
+    pub fn add_group_layer(&mut self, prefix: &str, layer: Layer<'a>) -> Result<(), HttpError> {
+        self.groups.push((Pattern::try_from(prefix)?, layer));
+
+        Ok(())
+    }
+
+    pub fn add_route_layer(
+        &mut self,
+        method: Method,
+        pattern: &str,
+        layer: Layer<'a>,
+    ) -> Result<(), HttpError> {
+        let pattern = Pattern::try_from(pattern)?;
+
+        let (_, _, layers) = self
+            .routes
+            .iter_mut()
+            .find(|(route, ..)| route.method == method && route.pattern == pattern)
+            .ok_or_else(|| HttpError::new(ErrorKind::InvalidRoute, "unknown route"))?;
+
+        layers.push(layer);
+
+        Ok(())
+    }
+
+    pub fn handle_request(&mut self, request: Request) -> Response {
+        let mut layers = mem::take(&mut self.layers);
+
+        let response = around(
+            &mut layers.iter_mut().collect::<Vec<_>>(),
+            request,
+            |request| self.route(request),
+        );
+
+        self.layers = layers;
+
+        response
+    }
+
+    fn route(&mut self, request: Request) -> Response {
+        let segments = segments(&request);
+
+        if let Some((index, params)) = self.find(&request.method, segments) {
+            return self.call(index, request, params);
+        }
+
+        if request.method == Method::Head {
+            if let Some((index, params)) = self.find(&Method::Get, segments) {
+                let mut response = self.call(index, request, params);
+
+                let is_chunked = is_chunked(response.headers.get_all("Transfer-Encoding"));
+
+                if !is_chunked && !response.headers.contains("Content-Length") {
+                    response
+                        .headers
+                        .insert("Content-Length", response.body.len().to_string().as_str());
+                }
+
+                response.body.clear();
+
+                return response;
+            }
+        }
+
+        if let Method::Extension(_) = request.method {
+            if !self
+                .routes
+                .iter()
+                .any(|(route, ..)| route.method == request.method)
+            {
+                return empty_response(StatusCode::NotImplemented, Headers::new());
+            }
+        }
+
+        if let Some(allowed) = self.allowed_methods(segments) {
+            let code = if request.method == Method::Options {
+                StatusCode::NoContent
+            } else {
+                StatusCode::MethodNotAllowed
+            };
+
+            let mut headers = Headers::new();
+            headers.insert("Allow", allowed.as_str());
+
+            let mut layers = group_layers(&mut self.groups, segments);
+
+            return around(&mut layers, request, |_| empty_response(code, headers));
+        }
+
+        (self.not_found_handler)(request, Params::new()).unwrap_or_else(Response::from)
+    }
+
+    fn find(&mut self, method: &Method, segments: &[String]) -> Option<(usize, Params)> {
+        let index = self
+            .tree
+            .find(segments, &mut self.captures, &mut |other, _| {
+                other == method
+            })?;
+
+        let params = self.routes[index]
+            .0
+            .pattern
+            .parameters(segments, &self.captures);
+
+        Some((index, Params::from(params)))
+    }
+
+    fn call(&mut self, index: usize, request: Request, params: Params) -> Response {
+        let (_, handler, route_layers) = &mut self.routes[index];
+
+        let mut layers = group_layers(&mut self.groups, segments(&request));
+        layers.extend(route_layers.iter_mut());
+
+        around(&mut layers, request, |request| {
+            handler(request, params).unwrap_or_else(Response::from)
+        })
+    }
+
+    fn allowed_methods(&mut self, segments: &[String]) -> Option<String> {
+        self.tree
+            .find(segments, &mut self.captures, &mut |_, _| true)?;
+
+        let routes = &self.routes;
+        let mut methods: Vec<&Method> = vec![];
+
+        self.tree
+            .find(segments, &mut self.captures, &mut |_, route| {
+                let method = &routes[route].0.method;
+
+                if !methods.contains(&method) {
+                    methods.push(method);
+                }
+
+                false
+            });
+
+        if methods.contains(&&Method::Get) && !methods.contains(&&Method::Head) {
+            methods.push(&Method::Head);
+        }
+
+        if !methods.contains(&&Method::Options) {
+            methods.push(&Method::Options);
+        }
+
+        Some(
+            methods
+                .iter()
+                .map(|method| method.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+}
+
+fn segments(request: &Request) -> &[String] {
+    match &request.uri.path {
+        Some(path) => path.segments.as_slice(),
+        None => &[],
+    }
+}
+
+fn group_layers<'g, 'a>(
+    groups: &'g mut [(Pattern, Layer<'a>)],
+    segments: &[String],
+) -> Vec<&'g mut Layer<'a>> {
+    groups
+        .iter_mut()
+        .filter(|(prefix, _)| prefix.is_prefix_match(segments))
+        .map(|(_, layer)| layer)
+        .collect()
+}
+
+fn empty_response(code: StatusCode, mut headers: Headers) -> Response {
+    if code != StatusCode::NoContent {
+        headers.insert("Content-Length", "0");
+    }
+
+    Response {
+        version: Version::Http11,
+        status: Status::from(code),
+        headers,
+        body: vec![],
+        trailers: Headers::new(),
+    }
+}
+
+fn around(
+    layers: &mut [&mut Layer<'_>],
+    mut request: Request,
+    inner: impl FnOnce(Request) -> Response,
+) -> Response {
+    for index in 0..layers.len() {
+        match layers[index].before(request) {
+            Ok(next) => request = next,
+            Err(response) => {
+                return layers[..index]
+                    .iter_mut()
+                    .rev()
+                    .fold(response, |response, layer| layer.after(response))
+            }
+        }
+    }
+
+    let response = inner(request);
+
+    layers
+        .iter_mut()
+        .rev()
+        .fold(response, |response, layer| layer.after(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+
+    fn response(code: StatusCode, body: &str) -> Response {
+        Response {
+            version: Version::Http11,
+            status: Status::from(code),
+            headers: Headers::new(),
+            body: body.as_bytes().to_vec(),
+            trailers: Headers::new(),
+        }
+    }
+
+    fn router<'a>() -> HttpRouter<'a> {
+        let mut router = HttpRouter::new(Box::new(|_, _| Ok(response(StatusCode::NotFound, ""))));
+
+        router
+            .add_route(
+                Method::Get,
+                "/devices/{id:u32}/channels/{channel}",
+                Box::new(|_, params| {
+                    let id: u8 = params.get("id")?;
+                    let channel: String = params.get("channel")?;
+
+                    Ok(response(
+                        StatusCode::Ok,
+                        (id.to_string() + " " + channel.as_str()).as_str(),
+                    ))
+                }),
+            )
+            .unwrap();
+
+        router
+    }
+
+    fn request(src: &str) -> Request {
+        Request::try_from(src).unwrap()
+    }
+
+    #[test]
+    fn handle_request_params_test() {
+        assert_eq!(
+            router().handle_request(request(
+                "GET /devices/7/channels/living%20room HTTP/1.1\r\nHost: example.com\r\n\r\n"
+            )),
+            response(StatusCode::Ok, "7 living room")
+        );
+    }
+
+    #[test]
+    fn handle_request_kind_mismatch_test() {
+        assert_eq!(
+            router()
+                .handle_request(request(
+                    "GET /devices/x/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::NotFound
+        );
+    }
+
+    #[test]
+    fn handle_request_conversion_error_test() {
+        assert_eq!(
+            router()
+                .handle_request(request(
+                    "GET /devices/256/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::BadRequest
+        );
+    }
+
+    #[test]
+    fn add_route_invalid_pattern_test() {
+        assert_eq!(
+            router()
+                .add_route(
+                    Method::Get,
+                    "/devices/{id",
+                    Box::new(|_, _| Ok(response(StatusCode::Ok, "")))
+                )
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRoute)
+        );
+    }
+
+    #[test]
+    fn handle_request_precedence_test() {
+        let mut router = router();
+
+        for (pattern, body) in [
+            ("/devices/*rest", "catch-all"),
+            ("/devices/{id}", "parameter"),
+            ("/devices/status", "static"),
+        ] {
+            router
+                .add_route(
+                    Method::Get,
+                    pattern,
+                    Box::new(move |_, _| Ok(response(StatusCode::Ok, body))),
+                )
+                .unwrap();
+        }
+
+        for (path, body) in [
+            ("/devices/status", "static"),
+            ("/devices/7", "parameter"),
+            ("/devices/7/channels", "catch-all"),
+        ] {
+            assert_eq!(
+                router
+                    .handle_request(request(
+                        ("GET ".to_string() + path + " HTTP/1.1\r\nHost: example.com\r\n\r\n")
+                            .as_str()
+                    ))
+                    .body,
+                body.as_bytes(),
+                "{}",
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn add_route_conflict_test() {
+        let mut router = router();
+        let handler = || Box::new(|_, _| Ok(response(StatusCode::Ok, "")));
+
+        assert_eq!(
+            router
+                .add_route(
+                    Method::Get,
+                    "/devices/{device}/channels/{name:u8}",
+                    handler()
+                )
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRoute)
+        );
+        assert_eq!(
+            router.add_route(Method::Post, "/devices/{id}/channels/{channel}", handler()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn handle_request_method_not_allowed_test() {
+        let mut router = router();
+        router
+            .add_route(
+                Method::Put,
+                "/devices/{id}/channels/{channel}",
+                Box::new(|_, _| Ok(response(StatusCode::Ok, ""))),
+            )
+            .unwrap();
+
+        let response = router.handle_request(request(
+            "DELETE /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::MethodNotAllowed);
+        assert_eq!(
+            response.headers.get("Allow"),
+            Some("GET, PUT, HEAD, OPTIONS")
+        );
+        assert_eq!(response.headers.get("Content-Length"), Some("0"));
+    }
+
+    #[test]
+    fn handle_request_allow_catch_all_test() {
+        let mut router = router();
+        router
+            .add_route(
+                Method::Post,
+                "/devices/*rest",
+                Box::new(|_, _| Ok(response(StatusCode::Ok, ""))),
+            )
+            .unwrap();
+
+        let allow = |router: &mut HttpRouter<'_>, path: &str| {
+            router
+                .handle_request(request(
+                    ("DELETE ".to_string() + path + " HTTP/1.1\r\nHost: example.com\r\n\r\n")
+                        .as_str(),
+                ))
+                .headers
+                .get("Allow")
+                .map(|allow| allow.to_string())
+        };
+
+        assert_eq!(
+            allow(&mut router, "/devices/7/channels/a"),
+            Some("GET, POST, HEAD, OPTIONS".to_string())
+        );
+        assert_eq!(
+            allow(&mut router, "/devices/x/channels/a"),
+            Some("POST, OPTIONS".to_string())
+        );
+    }
+
+    #[test]
+    fn handle_request_unknown_extension_method_test() {
+        assert_eq!(
+            router()
+                .handle_request(request(
+                    "PURGE /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::NotImplemented
+        );
+    }
+
+    #[test]
+    fn handle_request_not_found_test() {
+        assert_eq!(
+            router()
+                .handle_request(request(
+                    "DELETE /channels HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::NotFound
+        );
+    }
+
+    #[test]
+    fn handle_request_options_test() {
+        let response = router().handle_request(request(
+            "OPTIONS /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::NoContent);
+        assert_eq!(response.headers.get("Allow"), Some("GET, HEAD, OPTIONS"));
+        assert_eq!(response.headers.get("Content-Length"), None);
+        assert!(response.body.is_empty());
+    }
+
+    #[test]
+    fn handle_request_head_test() {
+        let response = router().handle_request(request(
+            "HEAD /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::Ok);
+        assert_eq!(response.headers.get("Content-Length"), Some("3"));
+        assert!(response.body.is_empty());
+        assert_eq!(
+            response.to_bytes(),
+            b"HTTP/1.1 200 OK\r\nContent-Length: 3\r\n\r\n".to_vec()
+        );
+    }
+
+    #[test]
+    fn handle_request_explicit_head_test() {
+        let mut router = router();
+        router
+            .add_route(
+                Method::Head,
+                "/devices/{id}/channels/{channel}",
+                Box::new(|_, _| Ok(response(StatusCode::NoContent, ""))),
+            )
+            .unwrap();
+
+        assert_eq!(
+            router
+                .handle_request(request(
+                    "HEAD /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::NoContent
+        );
+    }
+
+    struct Recorder {
+        name: &'static str,
+        log: Rc<RefCell<Vec<String>>>,
+        short_circuit: bool,
+    }
+
+    impl Middleware for Recorder {
+        fn before(&mut self, request: Request) -> Result<Request, Response> {
+            self.log
+                .borrow_mut()
+                .push("before ".to_string() + self.name);
+
+            if self.short_circuit {
+                Err(response(StatusCode::Unauthorized, ""))
+            } else {
+                Ok(request)
+            }
+        }
+
+        fn after(&mut self, response: Response) -> Response {
+            self.log.borrow_mut().push("after ".to_string() + self.name);
+
+            response
+        }
+    }
+
+    fn recorder(
+        name: &'static str,
+        log: &Rc<RefCell<Vec<String>>>,
+        short_circuit: bool,
+    ) -> Box<Recorder> {
+        Box::new(Recorder {
+            name,
+            log: log.clone(),
+            short_circuit,
+        })
+    }
+
+    const DEVICE_ROUTE: &str = "/devices/{id:u32}/channels/{channel}";
+
+    struct Cors;
+
+    impl Middleware for Cors {
+        fn after(&mut self, mut response: Response) -> Response {
+            response
+                .headers
+                .insert("Access-Control-Allow-Origin", "https://example.com");
+
+            response
+        }
+    }
+
+    #[test]
+    fn handle_request_group_layer_request_path_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut router = HttpRouter::new(Box::new(|_, _| Ok(response(StatusCode::NotFound, ""))));
+
+        router
+            .add_route(
+                Method::Get,
+                "/devices/{id:u32}",
+                Box::new(|_, _| Ok(response(StatusCode::Ok, ""))),
+            )
+            .unwrap();
+        router
+            .add_group_layer("/devices/{id}", recorder("parameter", &log, false))
+            .unwrap();
+        router
+            .add_group_layer("/devices/*", recorder("wildcard", &log, false))
+            .unwrap();
+        router
+            .add_group_layer(
+                "/devices/{name:i8}/channels",
+                recorder("other", &log, false),
+            )
+            .unwrap();
+
+        let response = router.handle_request(request(
+            "GET /devices/7 HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::Ok);
+        assert_eq!(
+            *log.borrow(),
+            [
+                "before parameter",
+                "before wildcard",
+                "after wildcard",
+                "after parameter"
+            ]
+        );
+
+        router
+            .add_group_layer("/devices/*", recorder("auth", &log, true))
+            .unwrap();
+
+        for src in [
+            "GET /devices/7 HTTP/1.1\r\nHost: example.com\r\n\r\n",
+            "DELETE /devices/7 HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ] {
+            assert_eq!(
+                router.handle_request(request(src)).status.code,
+                StatusCode::Unauthorized,
+                "{}",
+                src
+            );
+        }
+    }
+
+    #[test]
+    fn handle_request_group_layer_preflight_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut router = router();
+
+        router.add_group_layer("/devices", Box::new(Cors)).unwrap();
+        router
+            .add_group_layer("/devices/{id:u32}", recorder("group", &log, false))
+            .unwrap();
+        router
+            .add_group_layer("/channels", recorder("other group", &log, false))
+            .unwrap();
+        router
+            .add_route_layer(Method::Get, DEVICE_ROUTE, recorder("route", &log, false))
+            .unwrap();
+
+        let response = router.handle_request(request(
+            "OPTIONS /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\nOrigin: https://example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::NoContent);
+        assert_eq!(
+            response.headers.get("Access-Control-Allow-Origin"),
+            Some("https://example.com")
+        );
+        assert_eq!(*log.borrow(), ["before group", "after group"]);
+
+        let response = router.handle_request(request(
+            "DELETE /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n",
+        ));
+
+        assert_eq!(response.status.code, StatusCode::MethodNotAllowed);
+        assert_eq!(
+            response.headers.get("Access-Control-Allow-Origin"),
+            Some("https://example.com")
+        );
+    }
+
+    #[test]
+    fn handle_request_layer_order_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut router = router();
+
+        router
+            .add_route_layer(Method::Get, DEVICE_ROUTE, recorder("route", &log, false))
+            .unwrap();
+        router
+            .add_group_layer("/devices", recorder("group", &log, false))
+            .unwrap();
+        router
+            .add_group_layer("/channels", recorder("other group", &log, false))
+            .unwrap();
+        router.add_layer(recorder("global", &log, false));
+
+        assert_eq!(
+            router
+                .handle_request(request(
+                    "GET /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::Ok
+        );
+        assert_eq!(
+            *log.borrow(),
+            [
+                "before global",
+                "before group",
+                "before route",
+                "after route",
+                "after group",
+                "after global"
+            ]
+        );
+    }
+
+    #[test]
+    fn handle_request_short_circuit_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut router = router();
+
+        router.add_layer(recorder("global", &log, false));
+        router
+            .add_group_layer("/devices", recorder("auth", &log, true))
+            .unwrap();
+        router
+            .add_route_layer(Method::Get, DEVICE_ROUTE, recorder("route", &log, false))
+            .unwrap();
+
+        assert_eq!(
+            router
+                .handle_request(request(
+                    "GET /devices/7/channels/a HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::Unauthorized
+        );
+        assert_eq!(
+            *log.borrow(),
+            ["before global", "before auth", "after global"]
+        );
+    }
+
+    #[test]
+    fn handle_request_global_layer_not_found_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+        let mut router = router();
+
+        router.add_layer(recorder("global", &log, false));
+        router
+            .add_group_layer("/devices", recorder("group", &log, false))
+            .unwrap();
+
+        assert_eq!(
+            router
+                .handle_request(request(
+                    "GET /channels HTTP/1.1\r\nHost: example.com\r\n\r\n"
+                ))
+                .status
+                .code,
+            StatusCode::NotFound
+        );
+        assert_eq!(*log.borrow(), ["before global", "after global"]);
+    }
+
+    #[test]
+    fn add_route_layer_unknown_route_test() {
+        let log = Rc::new(RefCell::new(vec![]));
+
+        assert_eq!(
+            router()
+                .add_route_layer(Method::Post, DEVICE_ROUTE, recorder("route", &log, false))
+                .map_err(|error| error.kind),
+            Err(ErrorKind::InvalidRoute)
+        );
+    }
+}
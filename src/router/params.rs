@@ -0,0 +1,93 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use crate::error::{ErrorKind, HttpError};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<T, HttpError> {
+        self.get_str(name)
+            .ok_or_else(|| HttpError::new(ErrorKind::InvalidParameter, "unknown parameter"))?
+            .parse()
+            .map_err(|_| {
+                HttpError::new(
+                    ErrorKind::InvalidParameter,
+                    "parameter has an unexpected type",
+                )
+            })
+    }
+
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(parameter, _)| parameter == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.parameters
+            .iter()
+            .map(|(parameter, value)| (parameter.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+}
+
+impl From<Vec<(&str, String)>> for Params {
+    fn from(parameters: Vec<(&str, String)>) -> Self {
+        Self {
+            parameters: parameters
+                .into_iter()
+                .map(|(parameter, value)| (parameter.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+
+    #[test]
+    fn get_test() {
+        let params = Params::from(vec![("id", "7".to_string()), ("channel", "a".to_string())]);
+
+        assert_eq!(params.get::<u32>("id"), Ok(7));
+        assert_eq!(params.get::<String>("channel"), Ok("a".to_string()));
+        assert_eq!(params.get_str("channel"), Some("a"));
+        assert_eq!(params.len(), 2);
+    }
+
+    #[test]
+    fn get_invalid_test() {
+        let params = Params::from(vec![("channel", "a".to_string())]);
+
+        assert_eq!(
+            params.get::<u32>("channel").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidParameter)
+        );
+        assert_eq!(
+            params.get::<u32>("id").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidParameter)
+        );
+    }
+}
@@ -0,0 +1,418 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+use crate::error::{ErrorKind, HttpError};
+use crate::uri::percent;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterKind {
+    String,
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+}
+
+impl ParameterKind {
+    pub fn matches(&self, segment: &str) -> bool {
+        match self {
+            ParameterKind::String => !segment.is_empty(),
+            ParameterKind::U8 => segment.parse::<u8>().is_ok(),
+            ParameterKind::U16 => segment.parse::<u16>().is_ok(),
+            ParameterKind::U32 => segment.parse::<u32>().is_ok(),
+            ParameterKind::U64 => segment.parse::<u64>().is_ok(),
+            ParameterKind::I8 => segment.parse::<i8>().is_ok(),
+            ParameterKind::I16 => segment.parse::<i16>().is_ok(),
+            ParameterKind::I32 => segment.parse::<i32>().is_ok(),
+            ParameterKind::I64 => segment.parse::<i64>().is_ok(),
+        }
+    }
+}
+
+impl TryFrom<&str> for ParameterKind {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        match src {
+            "str" => Ok(ParameterKind::String),
+            "u8" => Ok(ParameterKind::U8),
+            "u16" => Ok(ParameterKind::U16),
+            "u32" => Ok(ParameterKind::U32),
+            "u64" => Ok(ParameterKind::U64),
+            "i8" => Ok(ParameterKind::I8),
+            "i16" => Ok(ParameterKind::I16),
+            "i32" => Ok(ParameterKind::I32),
+            "i64" => Ok(ParameterKind::I64),
+            _ => Err(HttpError::new(
+                ErrorKind::InvalidRoute,
+                "unknown parameter type",
+            )),
+        }
+    }
+}
+
+impl ToString for ParameterKind {
+    fn to_string(&self) -> String {
+        match self {
+            ParameterKind::String => "str".to_string(),
+            ParameterKind::U8 => "u8".to_string(),
+            ParameterKind::U16 => "u16".to_string(),
+            ParameterKind::U32 => "u32".to_string(),
+            ParameterKind::U64 => "u64".to_string(),
+            ParameterKind::I8 => "i8".to_string(),
+            ParameterKind::I16 => "i16".to_string(),
+            ParameterKind::I32 => "i32".to_string(),
+            ParameterKind::I64 => "i64".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Static(String),
+    Parameter {
+        name: String,
+        kind: ParameterKind,
+        optional: bool,
+    },
+    Wildcard,
+    CatchAll(Option<String>),
+}
+
+impl Segment {
+    fn name(&self) -> Option<&str> {
+        match self {
+            Segment::Parameter { name, .. } | Segment::CatchAll(Some(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn parse(src: &str) -> Result<Self, HttpError> {
+        if src == "*" {
+            return Ok(Segment::Wildcard);
+        } else if src == "**" {
+            return Ok(Segment::CatchAll(None));
+        } else if let Some(name) = src.strip_prefix('*') {
+            if !is_parameter_name(name) {
+                return Err(
+                    HttpError::new(ErrorKind::InvalidRoute, "invalid parameter name").offset(1),
+                );
+            }
+
+            return Ok(Segment::CatchAll(Some(name.to_string())));
+        }
+
+        if let Some(parameter) = src.strip_prefix('{') {
+            let parameter = parameter.strip_suffix('}').ok_or_else(|| {
+                HttpError::new(ErrorKind::InvalidRoute, "unterminated parameter").offset(src.len())
+            })?;
+
+            let (parameter, optional) = match parameter.strip_suffix('?') {
+                Some(parameter) => (parameter, true),
+                None => (parameter, false),
+            };
+
+            let (name, kind) = if let Some(index) = parameter.find(':') {
+                (
+                    &parameter[..index],
+                    ParameterKind::try_from(&parameter[index + 1..])
+                        .map_err(|error| error.offset(index + 2))?,
+                )
+            } else {
+                (parameter, ParameterKind::String)
+            };
+
+            if !is_parameter_name(name) {
+                return Err(
+                    HttpError::new(ErrorKind::InvalidRoute, "invalid parameter name").offset(1),
+                );
+            }
+
+            return Ok(Segment::Parameter {
+                name: name.to_string(),
+                kind,
+                optional,
+            });
+        }
+
+        if let Some(index) = src.find(['{', '}', '*']) {
+            return Err(HttpError::new(
+                ErrorKind::InvalidRoute,
+                "parameter must span a whole segment",
+            )
+            .offset(index));
+        }
+
+        percent::validate(src, ErrorKind::InvalidRoute, percent::is_pchar)?;
+
+        Ok(Segment::Static(percent::decode(src).map_err(|error| {
+            HttpError {
+                kind: ErrorKind::InvalidRoute,
+                ..error
+            }
+        })?))
+    }
+}
+
+impl ToString for Segment {
+    fn to_string(&self) -> String {
+        match self {
+            Segment::Static(segment) => percent::encode_path_segment(segment),
+            Segment::Parameter {
+                name,
+                kind,
+                optional,
+            } => {
+                let mut data = "{".to_string() + name.as_str();
+
+                if *kind != ParameterKind::String {
+                    data += ":";
+                    data += kind.to_string().as_str();
+                }
+
+                if *optional {
+                    data += "?";
+                }
+
+                data + "}"
+            }
+            Segment::Wildcard => "*".to_string(),
+            Segment::CatchAll(Some(name)) => "*".to_string() + name.as_str(),
+            Segment::CatchAll(None) => "**".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pattern {
+    pub segments: Vec<Segment>,
+}
+
+impl Pattern {
+    pub fn is_prefix_match(&self, segments: &[String]) -> bool {
+        let mut index = 0;
+
+        for pattern_segment in self.segments.iter() {
+            let segment = match (pattern_segment, segments.get(index)) {
+                (Segment::CatchAll(_), _) => return true,
+                (Segment::Parameter { optional: true, .. }, None) => continue,
+                (_, Some(segment)) => segment,
+                (_, None) => return false,
+            };
+
+            let is_match = match pattern_segment {
+                Segment::Static(pattern_segment) => pattern_segment == segment,
+                Segment::Parameter { kind, .. } => kind.matches(segment),
+                _ => !segment.is_empty(),
+            };
+
+            if !is_match {
+                return false;
+            }
+
+            index += 1;
+        }
+
+        true
+    }
+
+    pub(crate) fn parameters(
+        &self,
+        segments: &[String],
+        captures: &[Range<usize>],
+    ) -> Vec<(&str, String)> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Static(_) => None,
+                Segment::Parameter { name, .. } | Segment::CatchAll(Some(name)) => {
+                    Some(Some(name.as_str()))
+                }
+                Segment::Wildcard | Segment::CatchAll(None) => Some(None),
+            })
+            .zip(captures)
+            .filter_map(|(name, captured)| Some((name?, segments[captured.clone()].join("/"))))
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for Pattern {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        let relative_src = src.strip_prefix('/').ok_or_else(|| {
+            HttpError::new(ErrorKind::InvalidRoute, "pattern must start with a slash")
+        })?;
+
+        let mut segments: Vec<Segment> = Vec::new();
+
+        if relative_src.is_empty() {
+            return Ok(Self { segments });
+        }
+
+        let mut segment_start = 1;
+
+        for segment_src in relative_src.split('/') {
+            let segment =
+                Segment::parse(segment_src).map_err(|error| error.offset(segment_start))?;
+
+            let reason = match segments.last() {
+                Some(Segment::CatchAll(_)) => Some("catch-all must be the last segment"),
+                Some(Segment::Parameter { optional: true, .. })
+                    if !matches!(segment, Segment::Parameter { optional: true, .. }) =>
+                {
+                    Some("optional parameters must be trailing")
+                }
+                _ => None,
+            };
+
+            let reason = reason.or_else(|| {
+                let name = segment.name()?;
+
+                segments
+                    .iter()
+                    .any(|segment| segment.name() == Some(name))
+                    .then_some("duplicate parameter name")
+            });
+
+            if let Some(reason) = reason {
+                return Err(HttpError::new(ErrorKind::InvalidRoute, reason).offset(segment_start));
+            }
+
+            segments.push(segment);
+            segment_start += segment_src.len() + 1;
+        }
+
+        Ok(Self { segments })
+    }
+}
+
+impl ToString for Pattern {
+    fn to_string(&self) -> String {
+        let mut data = String::new();
+
+        for segment in self.segments.iter() {
+            data += "/";
+            data += segment.to_string().as_str();
+        }
+
+        if data.is_empty() {
+            data += "/";
+        }
+
+        data
+    }
+}
+
+fn is_parameter_name(src: &str) -> bool {
+    src.starts_with(|character: char| character.is_ascii_alphabetic() || character == '_')
+        && src
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+
+    #[test]
+    fn from_str_test() {
+        assert_eq!(
+            Pattern::try_from("/devices/{id:u32}/channels/{channel}"),
+            Ok(Pattern {
+                segments: vec![
+                    Segment::Static("devices".to_string()),
+                    Segment::Parameter {
+                        name: "id".to_string(),
+                        kind: ParameterKind::U32,
+                        optional: false,
+                    },
+                    Segment::Static("channels".to_string()),
+                    Segment::Parameter {
+                        name: "channel".to_string(),
+                        kind: ParameterKind::String,
+                        optional: false,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_root_test() {
+        assert_eq!(Pattern::try_from("/"), Ok(Pattern::default()));
+    }
+
+    #[test]
+    fn to_string_test() {
+        for src in [
+            "/",
+            "/devices/{id:u32}/channels/{channel}",
+            "/living%20room",
+        ] {
+            assert_eq!(Pattern::try_from(src).unwrap().to_string(), src.to_string());
+        }
+    }
+
+    #[test]
+    fn from_str_invalid_test() {
+        for (src, position) in [
+            ("devices", None),
+            ("/devices/{id", Some(12)),
+            ("/devices/{id:float}", Some(13)),
+            ("/devices/{}", Some(10)),
+            ("/devices/{1d}", Some(10)),
+            ("/devices/id{id}", Some(11)),
+            ("/devices/{id}/channels/{id}", Some(23)),
+            ("/living room", Some(7)),
+            ("/static/**/index", Some(11)),
+            ("/api/*rest/v1", Some(11)),
+            ("/api/*1", Some(6)),
+            ("/api/*rest/{rest}", Some(11)),
+            ("/logs/{page?}/latest", Some(14)),
+            ("/logs/a*b", Some(7)),
+        ] {
+            let error = Pattern::try_from(src).unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::InvalidRoute, "{}", src);
+            assert_eq!(error.position, position, "{}", src);
+        }
+    }
+
+    #[test]
+    fn is_prefix_match_test() {
+        let pattern = Pattern::try_from("/devices/{id:u32}").unwrap();
+
+        assert!(pattern.is_prefix_match(&["devices", "7"].map(|segment| segment.to_string())));
+        assert!(pattern
+            .is_prefix_match(&["devices", "7", "channels"].map(|segment| segment.to_string())));
+        assert!(!pattern.is_prefix_match(&["devices", "x"].map(|segment| segment.to_string())));
+        assert!(!pattern.is_prefix_match(&["devices".to_string()]));
+        assert!(Pattern::default().is_prefix_match(&["devices".to_string()]));
+    }
+
+    #[test]
+    fn parameters_test() {
+        let pattern = Pattern::try_from("/devices/{id:u32}/*/*rest").unwrap();
+        let segments = ["devices", "7", "a", "b", "c"].map(|segment| segment.to_string());
+
+        assert_eq!(
+            pattern.parameters(&segments, &[1..2, 2..3, 3..5]),
+            vec![("id", "7".to_string()), ("rest", "b/c".to_string())]
+        );
+
+        let pattern = Pattern::try_from("/logs/{page?}").unwrap();
+
+        assert_eq!(pattern.parameters(&["logs".to_string()], &[]), vec![]);
+    }
+}
@@ -0,0 +1,382 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::error::{ErrorKind, HttpError};
+use crate::method::Method;
+use crate::router::pattern::{ParameterKind, Segment};
+
+#[derive(Debug, Default)]
+pub(crate) struct Node {
+    statics: Vec<(String, Node)>,
+    parameters: Vec<(ParameterKind, Node)>,
+    catch_all: Vec<(Method, usize)>,
+    routes: Vec<(Method, usize)>,
+}
+
+impl Node {
+    pub(crate) fn insert(
+        &mut self,
+        segments: &[Segment],
+        method: Method,
+        route: usize,
+    ) -> Result<(), HttpError> {
+        if self.conflicts(segments, &method) {
+            return Err(HttpError::new(
+                ErrorKind::InvalidRoute,
+                "route conflicts with an existing route",
+            ));
+        }
+
+        self.add(segments, method, route);
+
+        Ok(())
+    }
+
+    pub(crate) fn find(
+        &self,
+        segments: &[String],
+        captures: &mut Vec<Range<usize>>,
+        accept: &mut impl FnMut(&Method, usize) -> bool,
+    ) -> Option<usize> {
+        captures.clear();
+
+        self.find_from(segments, 0, captures, accept)
+    }
+
+    fn find_from(
+        &self,
+        segments: &[String],
+        index: usize,
+        captures: &mut Vec<Range<usize>>,
+        accept: &mut impl FnMut(&Method, usize) -> bool,
+    ) -> Option<usize> {
+        if let Some(segment) = segments.get(index) {
+            if let Ok(position) = self.find_static(segment) {
+                if let Some(route) =
+                    self.statics[position]
+                        .1
+                        .find_from(segments, index + 1, captures, accept)
+                {
+                    return Some(route);
+                }
+            }
+
+            for (_, node) in self
+                .parameters
+                .iter()
+                .filter(|(kind, _)| kind.matches(segment))
+            {
+                captures.push(index..index + 1);
+
+                if let Some(route) = node.find_from(segments, index + 1, captures, accept) {
+                    return Some(route);
+                }
+
+                captures.pop();
+            }
+        } else if let Some(route) = Self::accept(&self.routes, accept) {
+            return Some(route);
+        }
+
+        let route = Self::accept(&self.catch_all, accept)?;
+        captures.push(index..segments.len());
+
+        Some(route)
+    }
+
+    fn add(&mut self, segments: &[Segment], method: Method, route: usize) {
+        match segments.split_first() {
+            None => self.routes.push((method, route)),
+            Some((Segment::CatchAll(_), _)) => self.catch_all.push((method, route)),
+            Some((Segment::Static(segment), rest)) => {
+                let index = match self.find_static(segment) {
+                    Ok(index) => index,
+                    Err(index) => {
+                        self.statics
+                            .insert(index, (segment.to_string(), Node::default()));
+                        index
+                    }
+                };
+
+                self.statics[index].1.add(rest, method, route)
+            }
+            Some((segment, rest)) => {
+                let kind = match segment {
+                    Segment::Parameter { kind, optional, .. } => {
+                        if *optional {
+                            self.routes.push((method.clone(), route));
+                        }
+
+                        *kind
+                    }
+                    _ => ParameterKind::String,
+                };
+
+                let index = match self.parameters.iter().position(|(other, _)| *other == kind) {
+                    Some(index) => index,
+                    None => {
+                        self.parameters.push((kind, Node::default()));
+                        self.parameters.len() - 1
+                    }
+                };
+
+                self.parameters[index].1.add(rest, method, route)
+            }
+        }
+    }
+
+    fn conflicts(&self, segments: &[Segment], method: &Method) -> bool {
+        let conflicts =
+            |routes: &[(Method, usize)]| routes.iter().any(|(other, _)| other == method);
+
+        match segments.split_first() {
+            None => conflicts(&self.routes),
+            Some((Segment::CatchAll(_), _)) => conflicts(&self.catch_all),
+            Some((Segment::Static(segment), rest)) => self
+                .find_static(segment)
+                .map(|index| self.statics[index].1.conflicts(rest, method))
+                .unwrap_or(false),
+            Some((segment, rest)) => {
+                (matches!(segment, Segment::Parameter { optional: true, .. })
+                    && conflicts(&self.routes))
+                    || self
+                        .parameters
+                        .iter()
+                        .any(|(_, node)| node.conflicts(rest, method))
+            }
+        }
+    }
+
+    fn accept(
+        routes: &[(Method, usize)],
+        accept: &mut impl FnMut(&Method, usize) -> bool,
+    ) -> Option<usize> {
+        routes
+            .iter()
+            .find(|(method, route)| accept(method, *route))
+            .map(|(_, route)| *route)
+    }
+
+    fn find_static(&self, segment: &str) -> Result<usize, usize> {
+        self.statics
+            .binary_search_by(|(other, _)| other.as_str().cmp(segment))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+    use core::convert::TryFrom;
+
+    use crate::router::pattern::Pattern;
+
+    fn tree(patterns: &[&str]) -> Node {
+        let mut node = Node::default();
+
+        for (route, pattern) in patterns.iter().enumerate() {
+            let pattern = Pattern::try_from(*pattern).unwrap();
+            node.insert(&pattern.segments, Method::Get, route).unwrap();
+        }
+
+        node
+    }
+
+    fn segments(path: &str) -> Vec<String> {
+        path.split('/')
+            .skip(1)
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string())
+            .collect()
+    }
+
+    fn find(node: &Node, path: &str) -> Option<usize> {
+        node.find(&segments(path), &mut Vec::new(), &mut |_, _| true)
+    }
+
+    #[test]
+    fn find_precedence_test() {
+        let node = tree(&["/devices/*rest", "/devices/{id}", "/devices/status", "/"]);
+
+        assert_eq!(find(&node, "/devices/status"), Some(2));
+        assert_eq!(find(&node, "/devices/7"), Some(1));
+        assert_eq!(find(&node, "/devices/7/channels"), Some(0));
+        assert_eq!(find(&node, "/devices"), Some(0));
+        assert_eq!(find(&node, "/"), Some(3));
+        assert_eq!(find(&node, "/status"), None);
+    }
+
+    #[test]
+    fn find_backtrack_test() {
+        let node = tree(&["/devices/status/a", "/devices/{id}/b"]);
+
+        assert_eq!(find(&node, "/devices/status/b"), Some(1));
+    }
+
+    #[test]
+    fn find_optional_test() {
+        let node = tree(&["/logs/{page?}"]);
+
+        assert_eq!(find(&node, "/logs"), Some(0));
+        assert_eq!(find(&node, "/logs/2"), Some(0));
+        assert_eq!(find(&node, "/logs/2/3"), None);
+    }
+
+    #[test]
+    fn find_kind_test() {
+        let node = tree(&["/devices/{id:u8}/a", "/devices/{name}/b"]);
+
+        assert_eq!(find(&node, "/devices/7/a"), Some(0));
+        assert_eq!(find(&node, "/devices/300/a"), None);
+        assert_eq!(find(&node, "/devices/7/b"), Some(1));
+    }
+
+    #[test]
+    fn find_captures_test() {
+        let node = tree(&["/devices/{id:u32}/*rest"]);
+        let mut captures = Vec::new();
+
+        assert_eq!(
+            node.find(
+                &segments("/devices/7/channels/a"),
+                &mut captures,
+                &mut |_, _| true
+            ),
+            Some(0)
+        );
+        assert_eq!(captures, vec![1..2, 2..4]);
+    }
+
+    fn parameters(pattern: &str, path: &str) -> Option<Vec<String>> {
+        let pattern = Pattern::try_from(pattern).unwrap();
+        let mut node = Node::default();
+        node.insert(&pattern.segments, Method::Get, 0).unwrap();
+
+        let segments = segments(path);
+        let mut captures = Vec::new();
+        node.find(&segments, &mut captures, &mut |_, _| true)?;
+
+        Some(
+            pattern
+                .parameters(&segments, &captures)
+                .into_iter()
+                .map(|(name, value)| name.to_string() + "=" + value.as_str())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn find_parameters_test() {
+        assert_eq!(
+            parameters(
+                "/devices/{id:u32}/channels/{channel}",
+                "/devices/7/channels/a"
+            ),
+            Some(vec!["id=7".to_string(), "channel=a".to_string()])
+        );
+    }
+
+    #[test]
+    fn find_kind_mismatch_test() {
+        for segment in ["256", "-1", "x"] {
+            assert_eq!(
+                parameters(
+                    "/devices/{id:u8}",
+                    ("/devices/".to_string() + segment).as_str()
+                ),
+                None,
+                "{}",
+                segment
+            );
+        }
+    }
+
+    #[test]
+    fn find_wildcard_test() {
+        assert_eq!(
+            parameters("/devices/*/status", "/devices/7/status"),
+            Some(vec![])
+        );
+        assert_eq!(parameters("/devices/*/status", "/devices/status"), None);
+    }
+
+    #[test]
+    fn find_catch_all_test() {
+        assert_eq!(
+            parameters("/api/v1/*rest", "/api/v1/devices/7"),
+            Some(vec!["rest=devices/7".to_string()])
+        );
+        assert_eq!(
+            parameters("/api/v1/*rest", "/api/v1"),
+            Some(vec!["rest=".to_string()])
+        );
+        assert_eq!(
+            parameters("/static/**", "/static/css/main.css"),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn find_optional_parameters_test() {
+        let pattern = "/logs/{page:u32?}/{size?}";
+
+        assert_eq!(parameters(pattern, "/logs"), Some(vec![]));
+        assert_eq!(
+            parameters(pattern, "/logs/2"),
+            Some(vec!["page=2".to_string()])
+        );
+        assert_eq!(
+            parameters(pattern, "/logs/2/10"),
+            Some(vec!["page=2".to_string(), "size=10".to_string()])
+        );
+        assert_eq!(parameters(pattern, "/logs/x"), None);
+    }
+
+    #[test]
+    fn find_method_test() {
+        let mut node = Node::default();
+        let pattern = Pattern::try_from("/devices/{id}").unwrap();
+        node.insert(&pattern.segments, Method::Get, 0).unwrap();
+        node.insert(&pattern.segments, Method::Put, 1).unwrap();
+
+        let segments = segments("/devices/7");
+        let mut methods = Vec::new();
+
+        assert_eq!(
+            node.find(&segments, &mut Vec::new(), &mut |method, _| {
+                *method == Method::Put
+            }),
+            Some(1)
+        );
+        assert_eq!(
+            node.find(&segments, &mut Vec::new(), &mut |method, _| {
+                methods.push(method.clone());
+                false
+            }),
+            None
+        );
+        assert_eq!(methods, vec![Method::Get, Method::Put]);
+    }
+
+    #[test]
+    fn insert_conflict_test() {
+        let mut node = tree(&["/devices/{id}", "/logs/{page?}"]);
+
+        for pattern in ["/devices/{name:u32}", "/devices/*", "/logs"] {
+            let pattern = Pattern::try_from(pattern).unwrap();
+
+            assert_eq!(
+                node.insert(&pattern.segments, Method::Get, 2)
+                    .map_err(|error| error.kind),
+                Err(ErrorKind::InvalidRoute)
+            );
+        }
+
+        let pattern = Pattern::try_from("/devices/{id}").unwrap();
+        assert_eq!(node.insert(&pattern.segments, Method::Put, 2), Ok(()));
+    }
+}
@@ -3,14 +3,17 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use core::convert::{From, TryFrom, TryInto};
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum StatusCode {
-    // 2xx
+    // 1xx
     Continue,
     SwitchingProtocols,
     Processing,
+    EarlyHints,
+
+    // 2xx
     Ok,
     Created,
     Accepted,
@@ -56,6 +59,7 @@ pub enum StatusCode {
     UnprocessableEntity,
     Locked,
     FailedDependency,
+    TooEarly,
     UpgradeRequired,
     PreconditionRequired,
     TooManyRequests,
@@ -74,18 +78,18 @@ pub enum StatusCode {
     LoopDetected,
     NotExtended,
     NetworkAuthenticationRequired,
-}
 
-impl TryFrom<&str> for StatusCode {
-    type Error = HttpError;
+    Other(u16),
+}
 
-    fn try_from(src: &str) -> Result<Self, Self::Error> {
-        let status_code = src.parse::<u32>().map_err(|_| HttpError::InvalidStatus)?;
-        match status_code {
+impl StatusCode {
+    pub fn from_u16(code: u16) -> Result<Self, HttpError> {
+        match code {
             // 1xx
             100 => Ok(StatusCode::Continue),
             101 => Ok(StatusCode::SwitchingProtocols),
             102 => Ok(StatusCode::Processing),
+            103 => Ok(StatusCode::EarlyHints),
 
             // 2xx
             200 => Ok(StatusCode::Ok),
@@ -133,6 +137,7 @@ impl TryFrom<&str> for StatusCode {
             422 => Ok(StatusCode::UnprocessableEntity),
             423 => Ok(StatusCode::Locked),
             424 => Ok(StatusCode::FailedDependency),
+            425 => Ok(StatusCode::TooEarly),
             426 => Ok(StatusCode::UpgradeRequired),
             428 => Ok(StatusCode::PreconditionRequired),
             429 => Ok(StatusCode::TooManyRequests),
@@ -152,85 +157,151 @@ impl TryFrom<&str> for StatusCode {
             510 => Ok(StatusCode::NotExtended),
             511 => Ok(StatusCode::NetworkAuthenticationRequired),
 
-            _ => Err(HttpError::InvalidStatus),
+            _ if (100..=999).contains(&code) => Ok(StatusCode::Other(code)),
+
+            _ => Err(HttpError::new(
+                ErrorKind::InvalidStatus,
+                "status code is not three digits",
+            )),
         }
     }
-}
 
-impl ToString for StatusCode {
-    fn to_string(&self) -> String {
+    pub fn as_u16(&self) -> u16 {
         match self {
             // 1xx
-            StatusCode::Continue => "100".to_string(),
-            StatusCode::SwitchingProtocols => "101".to_string(),
-            StatusCode::Processing => "102".to_string(),
+            StatusCode::Continue => 100,
+            StatusCode::SwitchingProtocols => 101,
+            StatusCode::Processing => 102,
+            StatusCode::EarlyHints => 103,
 
             // 2xx
-            StatusCode::Ok => "200".to_string(),
-            StatusCode::Created => "201".to_string(),
-            StatusCode::Accepted => "202".to_string(),
-            StatusCode::NonAuthoritativeInformation => "203".to_string(),
-            StatusCode::NoContent => "204".to_string(),
-            StatusCode::ResetContent => "205".to_string(),
-            StatusCode::PartialContent => "206".to_string(),
-            StatusCode::MultiStatus => "207".to_string(),
-            StatusCode::AlreadyReported => "208".to_string(),
-            StatusCode::ImUsed => "226".to_string(),
+            StatusCode::Ok => 200,
+            StatusCode::Created => 201,
+            StatusCode::Accepted => 202,
+            StatusCode::NonAuthoritativeInformation => 203,
+            StatusCode::NoContent => 204,
+            StatusCode::ResetContent => 205,
+            StatusCode::PartialContent => 206,
+            StatusCode::MultiStatus => 207,
+            StatusCode::AlreadyReported => 208,
+            StatusCode::ImUsed => 226,
 
             // 3xx
-            StatusCode::MultipleChoices => "300".to_string(),
-            StatusCode::MovedPermanently => "301".to_string(),
-            StatusCode::Found => "302".to_string(),
-            StatusCode::SeeOther => "303".to_string(),
-            StatusCode::NotModified => "304".to_string(),
-            StatusCode::UseProxy => "305".to_string(),
-            StatusCode::TemporaryRedirect => "307".to_string(),
-            StatusCode::PermanentRedirect => "308".to_string(),
+            StatusCode::MultipleChoices => 300,
+            StatusCode::MovedPermanently => 301,
+            StatusCode::Found => 302,
+            StatusCode::SeeOther => 303,
+            StatusCode::NotModified => 304,
+            StatusCode::UseProxy => 305,
+            StatusCode::TemporaryRedirect => 307,
+            StatusCode::PermanentRedirect => 308,
 
             // 4xx
-            StatusCode::BadRequest => "400".to_string(),
-            StatusCode::Unauthorized => "401".to_string(),
-            StatusCode::PaymentRequired => "402".to_string(),
-            StatusCode::Forbidden => "403".to_string(),
-            StatusCode::NotFound => "404".to_string(),
-            StatusCode::MethodNotAllowed => "405".to_string(),
-            StatusCode::NotAcceptable => "406".to_string(),
-            StatusCode::ProxyAuthenticationRequired => "407".to_string(),
-            StatusCode::RequestTimeout => "408".to_string(),
-            StatusCode::Conflict => "409".to_string(),
-            StatusCode::Gone => "410".to_string(),
-            StatusCode::LengthRequired => "411".to_string(),
-            StatusCode::PreconditionFailed => "412".to_string(),
-            StatusCode::PayloadTooLarge => "413".to_string(),
-            StatusCode::UriTooLong => "414".to_string(),
-            StatusCode::UnsupportedMediaType => "415".to_string(),
-            StatusCode::RangeNotSatisfiable => "416".to_string(),
-            StatusCode::ExpectationFailed => "417".to_string(),
-            StatusCode::ImATeapot => "418".to_string(),
-            StatusCode::MisdirectedRequest => "412".to_string(),
-            StatusCode::UnprocessableEntity => "422".to_string(),
-            StatusCode::Locked => "423".to_string(),
-            StatusCode::FailedDependency => "424".to_string(),
-            StatusCode::UpgradeRequired => "426".to_string(),
-            StatusCode::PreconditionRequired => "428".to_string(),
-            StatusCode::TooManyRequests => "429".to_string(),
-            StatusCode::RequestHeaderFieldsTooLarge => "431".to_string(),
-            StatusCode::UnavailableForLegalReasons => "451".to_string(),
+            StatusCode::BadRequest => 400,
+            StatusCode::Unauthorized => 401,
+            StatusCode::PaymentRequired => 402,
+            StatusCode::Forbidden => 403,
+            StatusCode::NotFound => 404,
+            StatusCode::MethodNotAllowed => 405,
+            StatusCode::NotAcceptable => 406,
+            StatusCode::ProxyAuthenticationRequired => 407,
+            StatusCode::RequestTimeout => 408,
+            StatusCode::Conflict => 409,
+            StatusCode::Gone => 410,
+            StatusCode::LengthRequired => 411,
+            StatusCode::PreconditionFailed => 412,
+            StatusCode::PayloadTooLarge => 413,
+            StatusCode::UriTooLong => 414,
+            StatusCode::UnsupportedMediaType => 415,
+            StatusCode::RangeNotSatisfiable => 416,
+            StatusCode::ExpectationFailed => 417,
+            StatusCode::ImATeapot => 418,
+            StatusCode::MisdirectedRequest => 421,
+            StatusCode::UnprocessableEntity => 422,
+            StatusCode::Locked => 423,
+            StatusCode::FailedDependency => 424,
+            StatusCode::TooEarly => 425,
+            StatusCode::UpgradeRequired => 426,
+            StatusCode::PreconditionRequired => 428,
+            StatusCode::TooManyRequests => 429,
+            StatusCode::RequestHeaderFieldsTooLarge => 431,
+            StatusCode::UnavailableForLegalReasons => 451,
 
             // 5xx
-            StatusCode::InternalServerError => "500".to_string(),
-            StatusCode::NotImplemented => "501".to_string(),
-            StatusCode::BadGateway => "502".to_string(),
-            StatusCode::ServiceUnavailable => "503".to_string(),
-            StatusCode::GatewayTimeout => "504".to_string(),
-            StatusCode::HttpVersionNotSupported => "505".to_string(),
-            StatusCode::VariantAlsoNegotiates => "506".to_string(),
-            StatusCode::InsufficientStorage => "507".to_string(),
-            StatusCode::LoopDetected => "508".to_string(),
-            StatusCode::NotExtended => "510".to_string(),
-            StatusCode::NetworkAuthenticationRequired => "511".to_string(),
+            StatusCode::InternalServerError => 500,
+            StatusCode::NotImplemented => 501,
+            StatusCode::BadGateway => 502,
+            StatusCode::ServiceUnavailable => 503,
+            StatusCode::GatewayTimeout => 504,
+            StatusCode::HttpVersionNotSupported => 505,
+            StatusCode::VariantAlsoNegotiates => 506,
+            StatusCode::InsufficientStorage => 507,
+            StatusCode::LoopDetected => 508,
+            StatusCode::NotExtended => 510,
+            StatusCode::NetworkAuthenticationRequired => 511,
+
+            StatusCode::Other(code) => *code,
         }
     }
+
+    pub fn is_informational(&self) -> bool {
+        (100..200).contains(&self.as_u16())
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.as_u16())
+    }
+
+    pub fn is_redirection(&self) -> bool {
+        (300..400).contains(&self.as_u16())
+    }
+
+    pub fn is_client_error(&self) -> bool {
+        (400..500).contains(&self.as_u16())
+    }
+
+    pub fn is_server_error(&self) -> bool {
+        (500..600).contains(&self.as_u16())
+    }
+}
+
+impl PartialEq for StatusCode {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_u16() == other.as_u16()
+    }
+}
+
+impl TryFrom<&str> for StatusCode {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        if src.len() != 3 || !src.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(HttpError::new(
+                ErrorKind::InvalidStatus,
+                "status code is not three digits",
+            ));
+        }
+
+        Self::from_u16(src.parse().unwrap())
+    }
+}
+
+impl From<ErrorKind> for StatusCode {
+    fn from(kind: ErrorKind) -> Self {
+        match kind {
+            ErrorKind::UnsupportedVersion => StatusCode::HttpVersionNotSupported,
+            ErrorKind::UriTooLong => StatusCode::UriTooLong,
+            ErrorKind::HeaderFieldsTooLarge => StatusCode::RequestHeaderFieldsTooLarge,
+            ErrorKind::PayloadTooLarge => StatusCode::PayloadTooLarge,
+            _ => StatusCode::BadRequest,
+        }
+    }
+}
+
+impl ToString for StatusCode {
+    fn to_string(&self) -> String {
+        self.as_u16().to_string()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -246,20 +317,21 @@ impl From<StatusCode> for Status {
             StatusCode::Continue => (status_code, "Continue".to_string()),
             StatusCode::SwitchingProtocols => (status_code, "Switching Protocols".to_string()),
             StatusCode::Processing => (status_code, "Processing".to_string()),
+            StatusCode::EarlyHints => (status_code, "Early Hints".to_string()),
 
             // 2xx
-            StatusCode::Ok => (status_code, "Ok".to_string()),
+            StatusCode::Ok => (status_code, "OK".to_string()),
             StatusCode::Created => (status_code, "Created".to_string()),
             StatusCode::Accepted => (status_code, "Accepted".to_string()),
             StatusCode::NonAuthoritativeInformation => {
-                (status_code, "Non Authoritative Information".to_string())
+                (status_code, "Non-Authoritative Information".to_string())
             }
             StatusCode::NoContent => (status_code, "No Content".to_string()),
             StatusCode::ResetContent => (status_code, "Reset Content".to_string()),
             StatusCode::PartialContent => (status_code, "Partial Content".to_string()),
-            StatusCode::MultiStatus => (status_code, "Multi Status".to_string()),
+            StatusCode::MultiStatus => (status_code, "Multi-Status".to_string()),
             StatusCode::AlreadyReported => (status_code, "Already Reported".to_string()),
-            StatusCode::ImUsed => (status_code, "Im Used".to_string()),
+            StatusCode::ImUsed => (status_code, "IM Used".to_string()),
 
             // 3xx
             StatusCode::MultipleChoices => (status_code, "Multiple Choices".to_string()),
@@ -287,16 +359,17 @@ impl From<StatusCode> for Status {
             StatusCode::Gone => (status_code, "Gone".to_string()),
             StatusCode::LengthRequired => (status_code, "Length Required".to_string()),
             StatusCode::PreconditionFailed => (status_code, "Precondition Failed".to_string()),
-            StatusCode::PayloadTooLarge => (status_code, "Payload Too Large".to_string()),
-            StatusCode::UriTooLong => (status_code, "Uri Too Long".to_string()),
+            StatusCode::PayloadTooLarge => (status_code, "Content Too Large".to_string()),
+            StatusCode::UriTooLong => (status_code, "URI Too Long".to_string()),
             StatusCode::UnsupportedMediaType => (status_code, "Unsupported Media Type".to_string()),
             StatusCode::RangeNotSatisfiable => (status_code, "Range Not Satisfiable".to_string()),
             StatusCode::ExpectationFailed => (status_code, "Expectation Failed".to_string()),
-            StatusCode::ImATeapot => (status_code, "Im A Teapot".to_string()),
+            StatusCode::ImATeapot => (status_code, "I'm a teapot".to_string()),
             StatusCode::MisdirectedRequest => (status_code, "Misdirected Request".to_string()),
-            StatusCode::UnprocessableEntity => (status_code, "Unprocessable Entity".to_string()),
+            StatusCode::UnprocessableEntity => (status_code, "Unprocessable Content".to_string()),
             StatusCode::Locked => (status_code, "Locked".to_string()),
             StatusCode::FailedDependency => (status_code, "Failed Dependency".to_string()),
+            StatusCode::TooEarly => (status_code, "Too Early".to_string()),
             StatusCode::UpgradeRequired => (status_code, "Upgrade Required".to_string()),
             StatusCode::PreconditionRequired => (status_code, "Precondition Required".to_string()),
             StatusCode::TooManyRequests => (status_code, "Too Many Requests".to_string()),
@@ -314,7 +387,7 @@ impl From<StatusCode> for Status {
             StatusCode::ServiceUnavailable => (status_code, "Service Unavailable".to_string()),
             StatusCode::GatewayTimeout => (status_code, "Gateway Timeout".to_string()),
             StatusCode::HttpVersionNotSupported => {
-                (status_code, "Http Version Not Supported".to_string())
+                (status_code, "HTTP Version Not Supported".to_string())
             }
             StatusCode::VariantAlsoNegotiates => {
                 (status_code, "Variant Also Negotiates".to_string())
@@ -325,6 +398,8 @@ impl From<StatusCode> for Status {
             StatusCode::NetworkAuthenticationRequired => {
                 (status_code, "Network Authentication Required".to_string())
             }
+
+            StatusCode::Other(_) => (status_code, String::new()),
         };
 
         Self { code, reason }
@@ -334,20 +409,17 @@ impl From<StatusCode> for Status {
 impl TryFrom<&str> for Status {
     type Error = HttpError;
 
-    fn try_from(mut src: &str) -> Result<Self, Self::Error> {
-        let code = if let Some(index) = src.find(" ") {
-            let method_split = src.split_at(index);
-
-            src = &method_split.1[1..];
-
-            method_split.0.try_into()?
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        let (code, reason) = if let Some(index) = src.find(' ') {
+            (src[..index].try_into()?, &src[index + 1..])
         } else {
-            return Err(HttpError::Exhausted);
+            (src.try_into()?, "")
         };
 
-        let reason = src.to_string();
-
-        Ok(Self { code, reason })
+        Ok(Self {
+            code,
+            reason: reason.to_string(),
+        })
     }
 }
 
@@ -370,10 +442,10 @@ mod tests {
     #[test]
     fn from_str_status_code_ok_test() {
         assert_eq!(
-            Status::try_from("200 Ok"),
+            Status::try_from("200 OK"),
             Ok(Status {
                 code: StatusCode::Ok,
-                reason: "Ok".to_string(),
+                reason: "OK".to_string(),
             })
         );
     }
@@ -383,10 +455,10 @@ mod tests {
         assert_eq!(
             Status {
                 code: StatusCode::Ok,
-                reason: "Ok".to_string(),
+                reason: "OK".to_string(),
             }
             .to_string(),
-            "200 Ok".to_string(),
+            "200 OK".to_string(),
         );
     }
 
@@ -419,7 +491,7 @@ mod tests {
             Status::from(StatusCode::Ok),
             Status {
                 code: StatusCode::Ok,
-                reason: "Ok".to_string(),
+                reason: "OK".to_string(),
             }
         );
     }
@@ -434,4 +506,104 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn from_str_unknown_status_code_test() {
+        assert_eq!(
+            Status::try_from("499 Client Closed Request"),
+            Ok(Status {
+                code: StatusCode::Other(499),
+                reason: "Client Closed Request".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_status_code_test() {
+        for src in ["99 Low", "1000 High", "abc Letters", "+20 Plus", "020 Zero"] {
+            assert_eq!(
+                Status::try_from(src).map_err(|error| error.kind),
+                Err(ErrorKind::InvalidStatus),
+                "{}",
+                src
+            );
+        }
+    }
+
+    #[test]
+    fn from_u16_test() {
+        assert_eq!(StatusCode::from_u16(103), Ok(StatusCode::EarlyHints));
+        assert_eq!(
+            StatusCode::from_u16(421),
+            Ok(StatusCode::MisdirectedRequest)
+        );
+        assert_eq!(StatusCode::from_u16(299), Ok(StatusCode::Other(299)));
+        assert_eq!(
+            StatusCode::from_u16(42).map_err(|error| error.kind),
+            Err(ErrorKind::InvalidStatus)
+        );
+    }
+
+    #[test]
+    fn as_u16_test() {
+        assert_eq!(StatusCode::Ok.as_u16(), 200);
+        assert_eq!(StatusCode::MisdirectedRequest.as_u16(), 421);
+        assert_eq!(StatusCode::TooEarly.as_u16(), 425);
+        assert_eq!(StatusCode::Other(499).as_u16(), 499);
+        assert_eq!(StatusCode::Other(499).to_string(), "499".to_string());
+    }
+
+    #[test]
+    fn other_equals_known_test() {
+        assert_eq!(StatusCode::Other(404), StatusCode::NotFound);
+        assert_ne!(StatusCode::Other(499), StatusCode::NotFound);
+    }
+
+    #[test]
+    fn round_trip_test() {
+        for code in 100..=999 {
+            assert_eq!(StatusCode::from_u16(code).unwrap().as_u16(), code);
+        }
+    }
+
+    #[test]
+    fn class_test() {
+        assert!(StatusCode::EarlyHints.is_informational());
+        assert!(StatusCode::NoContent.is_success());
+        assert!(StatusCode::PermanentRedirect.is_redirection());
+        assert!(StatusCode::Other(499).is_client_error());
+        assert!(StatusCode::BadGateway.is_server_error());
+        assert!(!StatusCode::Other(600).is_server_error());
+        assert!(!StatusCode::Ok.is_client_error());
+    }
+
+    #[test]
+    fn from_str_empty_reason_test() {
+        let status = Status {
+            code: StatusCode::Ok,
+            reason: String::new(),
+        };
+
+        assert_eq!(Status::try_from("200"), Ok(status.clone()));
+        assert_eq!(Status::try_from("200 "), Ok(status));
+    }
+
+    #[test]
+    fn from_status_code_iana_reason_test() {
+        for (code, reason) in [
+            (
+                StatusCode::NonAuthoritativeInformation,
+                "Non-Authoritative Information",
+            ),
+            (StatusCode::ImATeapot, "I'm a teapot"),
+            (StatusCode::UriTooLong, "URI Too Long"),
+            (
+                StatusCode::HttpVersionNotSupported,
+                "HTTP Version Not Supported",
+            ),
+            (StatusCode::Other(499), ""),
+        ] {
+            assert_eq!(Status::from(code).reason, reason);
+        }
+    }
 }
@@ -0,0 +1,205 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use core::convert::TryFrom;
+
+use crate::error::{ErrorKind, HttpError};
+use crate::method::Method;
+use crate::uri::authority::Authority;
+use crate::uri::path::Path;
+use crate::uri::query::Query;
+use crate::uri::Uri;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RequestTarget {
+    Origin { path: Path, query: Option<Query> },
+    Absolute(Uri),
+    Authority(Authority),
+    Asterisk,
+}
+
+impl RequestTarget {
+    pub fn parse(src: &str, method: &Method) -> Result<Self, HttpError> {
+        if *method == Method::Connect {
+            let authority = Authority::try_from(src)?;
+
+            if authority.username.is_some() || authority.port.is_none() {
+                return Err(HttpError::new(
+                    ErrorKind::InvalidUri,
+                    "authority form requires a host and port only",
+                ));
+            }
+
+            return Ok(RequestTarget::Authority(authority));
+        }
+
+        if src == "*" {
+            return if *method == Method::Options {
+                Ok(RequestTarget::Asterisk)
+            } else {
+                Err(HttpError::new(
+                    ErrorKind::InvalidUri,
+                    "asterisk form is only allowed for OPTIONS",
+                ))
+            };
+        }
+
+        if src.starts_with('/') {
+            let (path, query) = if let Some(index) = src.find('?') {
+                (
+                    &src[..index],
+                    Some(
+                        Query::try_from(&src[index + 1..])
+                            .map_err(|error| error.offset(index + 1))?,
+                    ),
+                )
+            } else {
+                (src, None)
+            };
+
+            return Ok(RequestTarget::Origin {
+                path: Path::try_from(path)?,
+                query,
+            });
+        }
+
+        let uri = Uri::try_from(src)?;
+
+        if uri.scheme.is_none() || uri.authority.is_none() {
+            return Err(HttpError::new(
+                ErrorKind::InvalidUri,
+                "request target is not in origin or absolute form",
+            ));
+        } else if let Some(index) = src.find('#') {
+            return Err(
+                HttpError::new(ErrorKind::InvalidUri, "fragment in request target").offset(index),
+            );
+        }
+
+        Ok(RequestTarget::Absolute(uri))
+    }
+}
+
+impl ToString for RequestTarget {
+    fn to_string(&self) -> String {
+        match self {
+            RequestTarget::Origin { path, query } => {
+                let mut data = path.to_string();
+
+                if let Some(query) = query {
+                    data += "?";
+                    data += query.to_string().as_str();
+                }
+
+                data
+            }
+            RequestTarget::Absolute(uri) => uri.to_string(),
+            RequestTarget::Authority(authority) => authority.to_string(),
+            RequestTarget::Asterisk => "*".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+
+    use crate::uri::host::Host;
+
+    #[test]
+    fn parse_origin_test() {
+        let parameters = vec![("parameter".to_string(), Some("value".to_string()))];
+
+        assert_eq!(
+            RequestTarget::parse("/resource?parameter=value", &Method::Get),
+            Ok(RequestTarget::Origin {
+                path: Path {
+                    segments: vec!["resource".to_string()],
+                    absolute: true,
+                },
+                query: Some(Query { parameters }),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_origin_double_slash_test() {
+        assert_eq!(
+            RequestTarget::parse("//resource", &Method::Get),
+            Ok(RequestTarget::Origin {
+                path: Path {
+                    segments: vec!["".to_string(), "resource".to_string()],
+                    absolute: true,
+                },
+                query: None,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_absolute_test() {
+        assert_eq!(
+            RequestTarget::parse("http://example.com/resource", &Method::Get),
+            Ok(RequestTarget::Absolute(
+                Uri::try_from("http://example.com/resource").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_authority_test() {
+        assert_eq!(
+            RequestTarget::parse("example.com:443", &Method::Connect),
+            Ok(RequestTarget::Authority(Authority {
+                username: None,
+                password: None,
+                host: Host::RegName("example.com".to_string()),
+                port: Some(443),
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_asterisk_test() {
+        assert_eq!(
+            RequestTarget::parse("*", &Method::Options),
+            Ok(RequestTarget::Asterisk)
+        );
+    }
+
+    #[test]
+    fn to_string_test() {
+        for (src, method) in [
+            ("/resource?parameter=value", Method::Get),
+            ("http://example.com/resource?parameter=value", Method::Get),
+            ("[fe80::1]:443", Method::Connect),
+            ("*", Method::Options),
+        ] {
+            assert_eq!(
+                RequestTarget::parse(src, &method).unwrap().to_string(),
+                src.to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_invalid_test() {
+        for (src, method) in [
+            ("*", Method::Get),
+            ("example.com", Method::Connect),
+            ("user@example.com:443", Method::Connect),
+            ("resource", Method::Get),
+            ("http://example.com/resource#fragment", Method::Get),
+            ("/resource#fragment", Method::Get),
+        ] {
+            assert_eq!(
+                RequestTarget::parse(src, &method).map_err(|error| error.kind),
+                Err(ErrorKind::InvalidUri),
+                "{}",
+                src
+            );
+        }
+    }
+}
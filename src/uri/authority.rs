@@ -3,55 +3,113 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
+use crate::uri::host::Host;
+use crate::uri::percent::{self, is_sub_delim, is_unreserved};
+use crate::uri::scheme::Scheme;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Authority {
     pub username: Option<String>,
     pub password: Option<String>,
-    pub host: String,
-    pub port: Option<String>,
+    pub host: Host,
+    pub port: Option<u16>,
+}
+
+impl Authority {
+    pub fn port_or_default(&self, scheme: &Scheme) -> Option<u16> {
+        self.port.or_else(|| scheme.default_port())
+    }
+
+    pub fn normalize(&self, scheme: Option<&Scheme>) -> Self {
+        let port = match (self.port, scheme) {
+            (Some(port), Some(scheme)) if Some(port) == scheme.default_port() => None,
+            (port, _) => port,
+        };
+
+        Self {
+            username: self.username.clone(),
+            password: self.password.clone(),
+            host: self.host.normalize(),
+            port,
+        }
+    }
 }
 
 impl TryFrom<&str> for Authority {
     type Error = HttpError;
 
     fn try_from(mut src: &str) -> Result<Self, Self::Error> {
+        let host_start = src.find('@').map(|index| index + 1).unwrap_or(0);
         let mut username_password_split = src.split("@");
 
         let (username, password) = if username_password_split.clone().count() == 2 {
             src = username_password_split.clone().nth(1).unwrap();
 
+            percent::validate(
+                username_password_split.clone().nth(0).unwrap(),
+                ErrorKind::InvalidAuthority,
+                |byte| is_unreserved(byte) || is_sub_delim(byte) || byte == b':',
+            )?;
+
             let mut username_split = username_password_split.nth(0).unwrap().split(":");
 
             if username_split.clone().count() == 2 {
                 (
-                    Some(username_split.nth(0).unwrap().to_string()),
-                    Some(username_split.nth(0).unwrap().to_string()),
+                    Some(percent::decode(username_split.nth(0).unwrap())?),
+                    Some(percent::decode(username_split.nth(0).unwrap())?),
                 )
             } else if username_split.clone().count() == 1 {
-                (Some(username_split.nth(0).unwrap().to_string()), None)
+                (Some(percent::decode(username_split.nth(0).unwrap())?), None)
             } else {
-                return Err(HttpError::InvalidAuthority);
+                return Err(HttpError::new(
+                    ErrorKind::InvalidAuthority,
+                    "more than one colon in userinfo",
+                ));
             }
         } else if username_password_split.count() == 1 {
             (None, None)
         } else {
-            return Err(HttpError::InvalidAuthority);
+            return Err(HttpError::new(
+                ErrorKind::InvalidAuthority,
+                "more than one at sign",
+            ));
         };
 
-        let mut port_split = src.split(":");
-
-        let port = if port_split.clone().count() == 2 {
-            src = port_split.nth(0).unwrap();
-            Some(port_split.nth(0).unwrap().to_string())
-        } else if port_split.count() == 1 {
-            None
+        let host_end = if src.starts_with('[') {
+            src.find(']').map(|index| index + 1).unwrap_or(src.len())
         } else {
-            return Err(HttpError::InvalidAuthority);
+            src.find(':').unwrap_or(src.len())
+        };
+
+        let port = match &src[host_end..] {
+            "" | ":" => None,
+            port_str if port_str.starts_with(':') => {
+                let port_str = &port_str[1..];
+
+                if !port_str.bytes().all(|byte| byte.is_ascii_digit()) {
+                    return Err(HttpError::new(
+                        ErrorKind::InvalidAuthority,
+                        "port is not a number",
+                    )
+                    .offset(host_start + host_end + 1));
+                }
+
+                Some(port_str.parse().map_err(|_| {
+                    HttpError::new(ErrorKind::InvalidAuthority, "port out of range")
+                        .offset(host_start + host_end + 1)
+                })?)
+            }
+            _ => {
+                return Err(HttpError::new(
+                    ErrorKind::InvalidAuthority,
+                    "unexpected characters after IP literal",
+                )
+                .offset(host_start + host_end))
+            }
         };
 
-        let host = src.to_string();
+        let host = Host::try_from(&src[..host_end]).map_err(|error| error.offset(host_start))?;
 
         Ok(Self {
             username,
@@ -67,21 +125,21 @@ impl ToString for Authority {
         let mut data = String::new();
 
         if let Some(username) = &self.username {
-            data += username.as_str();
+            data += percent::encode_userinfo(username).as_str();
 
             if let Some(password) = &self.password {
                 data += ":";
-                data += password.as_str();
+                data += percent::encode_userinfo(password).as_str();
             }
 
             data += "@";
         }
 
-        data += self.host.as_str();
+        data += self.host.to_string().as_str();
 
-        if let Some(port) = &self.port {
+        if let Some(port) = self.port {
             data += ":";
-            data += port.as_str();
+            data += port.to_string().as_str();
         }
 
         data
@@ -92,12 +150,14 @@ impl ToString for Authority {
 mod tests {
     use super::*;
 
+    use core::net::{Ipv4Addr, Ipv6Addr};
+
     #[test]
     fn from_str_full_test() {
         let username = Some("username".to_string());
         let password = Some("password".to_string());
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority::try_from("username:password@example.com:123"),
             Ok(Authority {
@@ -113,8 +173,8 @@ mod tests {
     fn to_string_full_test() {
         let username = Some("username".to_string());
         let password = Some("password".to_string());
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority {
                 username,
@@ -131,8 +191,8 @@ mod tests {
     fn from_str_no_password_test() {
         let username = Some("username".to_string());
         let password = None;
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority::try_from("username@example.com:123"),
             Ok(Authority {
@@ -148,8 +208,8 @@ mod tests {
     fn to_string_no_password_test() {
         let username = Some("username".to_string());
         let password = None;
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority {
                 username,
@@ -166,8 +226,8 @@ mod tests {
     fn from_str_no_username_test() {
         let username = None;
         let password = None;
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority::try_from("example.com:123"),
             Ok(Authority {
@@ -183,8 +243,8 @@ mod tests {
     fn to_string_no_username_test() {
         let username = None;
         let password = None;
-        let host = "example.com".to_string();
-        let port = Some("123".to_string());
+        let host = Host::RegName("example.com".to_string());
+        let port = Some(123);
         assert_eq!(
             Authority {
                 username,
@@ -201,7 +261,7 @@ mod tests {
     fn from_str_no_port_test() {
         let username = None;
         let password = None;
-        let host = "example.com".to_string();
+        let host = Host::RegName("example.com".to_string());
         let port = None;
         assert_eq!(
             Authority::try_from("example.com"),
@@ -218,7 +278,7 @@ mod tests {
     fn to_string_no_port_test() {
         let username = None;
         let password = None;
-        let host = "example.com".to_string();
+        let host = Host::RegName("example.com".to_string());
         let port = None;
         assert_eq!(
             Authority {
@@ -235,8 +295,130 @@ mod tests {
     #[test]
     fn from_str_invalid_authority_test() {
         assert_eq!(
-            Authority::try_from("username@password@example.com:123"),
-            Err(HttpError::InvalidAuthority)
+            Authority::try_from("username@password@example.com:123").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidAuthority)
+        );
+    }
+
+    #[test]
+    fn from_str_percent_encoded_userinfo_test() {
+        let authority = Authority::try_from("user%40home:pass%3Aword@example.com").unwrap();
+
+        assert_eq!(authority.username, Some("user@home".to_string()));
+        assert_eq!(authority.password, Some("pass:word".to_string()));
+        assert_eq!(
+            authority.to_string(),
+            "user%40home:pass%3Aword@example.com".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_ipv6_test() {
+        assert_eq!(
+            Authority::try_from("[fe80::1]:8080"),
+            Ok(Authority {
+                username: None,
+                password: None,
+                host: Host::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)),
+                port: Some(8080),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_ipv6_zone_id_test() {
+        let authority = Authority::try_from("[fe80::1%25eth0]:8080").unwrap();
+
+        assert_eq!(
+            authority.host,
+            Host::Ipv6Zoned(
+                Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1),
+                "eth0".to_string()
+            )
+        );
+        assert_eq!(authority.port, Some(8080));
+        assert_eq!(authority.to_string(), "[fe80::1%25eth0]:8080".to_string());
+    }
+
+    #[test]
+    fn to_string_ipv6_test() {
+        assert_eq!(
+            Authority {
+                username: None,
+                password: None,
+                host: Host::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)),
+                port: Some(8080),
+            }
+            .to_string(),
+            "[fe80::1]:8080".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_ipv4_test() {
+        assert_eq!(
+            Authority::try_from("user@192.168.1.10"),
+            Ok(Authority {
+                username: Some("user".to_string()),
+                password: None,
+                host: Host::Ipv4(Ipv4Addr::new(192, 168, 1, 10)),
+                port: None,
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_port_test() {
+        let error = Authority::try_from("example.com:65536").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidAuthority);
+        assert_eq!(error.position, Some(12));
+
+        assert_eq!(
+            Authority::try_from("example.com:80a").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidAuthority)
+        );
+        assert_eq!(
+            Authority::try_from("[fe80::1]8080").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidAuthority)
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_host_test() {
+        let error = Authority::try_from("user@exa mple.com:80").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidAuthority);
+        assert_eq!(error.position, Some(8));
+    }
+
+    #[test]
+    fn port_or_default_test() {
+        let authority = Authority::try_from("example.com").unwrap();
+        assert_eq!(authority.port_or_default(&Scheme::Http), Some(80));
+        assert_eq!(authority.port_or_default(&Scheme::Https), Some(443));
+        assert_eq!(
+            authority.port_or_default(&Scheme::Other("ross".to_string())),
+            None
+        );
+
+        let authority = Authority::try_from("example.com:8443").unwrap();
+        assert_eq!(authority.port_or_default(&Scheme::Https), Some(8443));
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            Authority::try_from("user@Example.COM:443")
+                .unwrap()
+                .normalize(Some(&Scheme::Https))
+                .to_string(),
+            "user@example.com".to_string()
+        );
+        assert_eq!(
+            Authority::try_from("Example.COM:443")
+                .unwrap()
+                .normalize(Some(&Scheme::Http))
+                .to_string(),
+            "example.com:443".to_string()
         );
     }
 }
@@ -0,0 +1,272 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::error::{ErrorKind, HttpError};
+use crate::uri::percent::{self, is_sub_delim, is_unreserved};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Host {
+    RegName(String),
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    Ipv6Zoned(Ipv6Addr, String),
+}
+
+impl Host {
+    pub fn normalize(&self) -> Self {
+        match self {
+            Host::RegName(reg_name) => {
+                let bytes = reg_name.as_bytes();
+                let mut data = Vec::with_capacity(bytes.len());
+                let mut position = 0;
+
+                while position < bytes.len() {
+                    let byte = bytes
+                        .get(position..position + 3)
+                        .filter(|octet| octet[0] == b'%')
+                        .and_then(|octet| core::str::from_utf8(&octet[1..]).ok())
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                    if let Some(byte) = byte {
+                        if is_unreserved(byte) {
+                            data.push(byte.to_ascii_lowercase());
+                        } else {
+                            data.extend(bytes[position..position + 3].to_ascii_uppercase());
+                        }
+
+                        position += 3;
+                    } else {
+                        data.push(bytes[position].to_ascii_lowercase());
+                        position += 1;
+                    }
+                }
+
+                Host::RegName(String::from_utf8_lossy(&data).into_owned())
+            }
+            _ => self.clone(),
+        }
+    }
+
+    pub fn as_reg_name(&self) -> Option<&str> {
+        match self {
+            Host::RegName(reg_name) => Some(reg_name.as_str()),
+            _ => None,
+        }
+    }
+
+    pub fn as_ipv4(&self) -> Option<Ipv4Addr> {
+        match self {
+            Host::Ipv4(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    pub fn as_ipv6(&self) -> Option<Ipv6Addr> {
+        match self {
+            Host::Ipv6(address) | Host::Ipv6Zoned(address, _) => Some(*address),
+            _ => None,
+        }
+    }
+
+    pub fn zone_id(&self) -> Option<&str> {
+        match self {
+            Host::Ipv6Zoned(_, zone_id) => Some(zone_id.as_str()),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&str> for Host {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        if let Some(literal) = src.strip_prefix('[') {
+            let literal = literal.strip_suffix(']').ok_or_else(|| {
+                HttpError::new(ErrorKind::InvalidAuthority, "unterminated IP literal")
+            })?;
+
+            if literal.starts_with(['v', 'V']) {
+                return Err(HttpError::new(
+                    ErrorKind::InvalidAuthority,
+                    "unsupported IP literal version",
+                )
+                .offset(1));
+            }
+
+            let zone_position = literal.find('%');
+
+            let address = literal[..zone_position.unwrap_or(literal.len())]
+                .parse()
+                .map_err(|_| {
+                    HttpError::new(ErrorKind::InvalidAuthority, "invalid IPv6 address").offset(1)
+                })?;
+
+            return match zone_position {
+                Some(position) => {
+                    let zone_id = literal[position..]
+                        .strip_prefix("%25")
+                        .filter(|zone_id| !zone_id.is_empty())
+                        .ok_or_else(|| {
+                            HttpError::new(ErrorKind::InvalidAuthority, "invalid IPv6 zone ID")
+                                .offset(position + 1)
+                        })?;
+
+                    percent::validate(zone_id, ErrorKind::InvalidAuthority, is_unreserved)
+                        .map_err(|error| error.offset(position + 4))?;
+
+                    Ok(Host::Ipv6Zoned(address, zone_id.to_string()))
+                }
+                None => Ok(Host::Ipv6(address)),
+            };
+        }
+
+        if let Ok(address) = src.parse() {
+            return Ok(Host::Ipv4(address));
+        }
+
+        percent::validate(src, ErrorKind::InvalidAuthority, |byte| {
+            is_unreserved(byte) || is_sub_delim(byte)
+        })?;
+
+        Ok(Host::RegName(src.to_string()))
+    }
+}
+
+impl ToString for Host {
+    fn to_string(&self) -> String {
+        match self {
+            Host::RegName(reg_name) => reg_name.clone(),
+            Host::Ipv4(address) => address.to_string(),
+            Host::Ipv6(address) => "[".to_string() + address.to_string().as_str() + "]",
+            Host::Ipv6Zoned(address, zone_id) => {
+                "[".to_string() + address.to_string().as_str() + "%25" + zone_id.as_str() + "]"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_reg_name_test() {
+        assert_eq!(
+            Host::try_from("example.com"),
+            Ok(Host::RegName("example.com".to_string()))
+        );
+    }
+
+    #[test]
+    fn from_str_ipv4_test() {
+        assert_eq!(
+            Host::try_from("192.168.1.10"),
+            Ok(Host::Ipv4(Ipv4Addr::new(192, 168, 1, 10)))
+        );
+    }
+
+    #[test]
+    fn from_str_ipv6_test() {
+        assert_eq!(
+            Host::try_from("[fe80::1]"),
+            Ok(Host::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn to_string_ipv6_test() {
+        assert_eq!(
+            Host::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1)).to_string(),
+            "[fe80::1]".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_ipv6_zone_id_test() {
+        let host = Host::try_from("[fe80::1%25eth0]").unwrap();
+
+        assert_eq!(
+            host,
+            Host::Ipv6Zoned(
+                Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1),
+                "eth0".to_string()
+            )
+        );
+        assert_eq!(
+            host.as_ipv6(),
+            Some(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))
+        );
+        assert_eq!(host.zone_id(), Some("eth0"));
+        assert_eq!(host.to_string(), "[fe80::1%25eth0]".to_string());
+    }
+
+    #[test]
+    fn from_str_invalid_ipv6_zone_id_test() {
+        for (src, position) in [
+            ("[fe80::1%eth0]", 8),
+            ("[fe80::1%25]", 8),
+            ("[fe80::1%25eth/0]", 14),
+            ("[fe80::1%25eth%2]", 14),
+        ]
+        .iter()
+        {
+            let error = Host::try_from(*src).unwrap_err();
+
+            assert_eq!(error.kind, ErrorKind::InvalidAuthority, "{}", src);
+            assert_eq!(error.position, Some(*position), "{}", src);
+        }
+    }
+
+    #[test]
+    fn accessors_test() {
+        let host = Host::Ipv4(Ipv4Addr::LOCALHOST);
+
+        assert_eq!(host.as_ipv4(), Some(Ipv4Addr::LOCALHOST));
+        assert_eq!(host.as_ipv6(), None);
+        assert_eq!(host.as_reg_name(), None);
+        assert_eq!(
+            Host::RegName("example.com".to_string()).as_reg_name(),
+            Some("example.com")
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_reg_name_test() {
+        let error = Host::try_from("exa mple.com").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidAuthority);
+        assert_eq!(error.position, Some(3));
+    }
+
+    #[test]
+    fn from_str_invalid_ipv6_test() {
+        assert_eq!(
+            Host::try_from("[fe80::g]").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidAuthority)
+        );
+        assert_eq!(
+            Host::try_from("[fe80::1").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidAuthority)
+        );
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            Host::try_from("Example%2dHost.COM%2f").unwrap().normalize(),
+            Host::RegName("example-host.com%2F".to_string())
+        );
+    }
+
+    #[test]
+    fn normalize_non_ascii_test() {
+        assert_eq!(
+            Host::RegName("Ünï%2DCode%".to_string()).normalize(),
+            Host::RegName("Ünï-code%".to_string())
+        );
+    }
+}
@@ -1,25 +1,96 @@
 extern crate alloc;
 
 use alloc::string::{String, ToString};
+use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
+use crate::uri::percent;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Path {
     pub segments: Vec<String>,
+    pub absolute: bool,
+}
+
+impl Path {
+    pub fn is_empty(&self) -> bool {
+        !self.absolute && self.segments.is_empty()
+    }
+
+    pub fn normalize(&self) -> Self {
+        let mut segments: Vec<String> = Vec::new();
+
+        for (index, segment) in self.segments.iter().enumerate() {
+            let last = index == self.segments.len() - 1;
+
+            match segment.as_str() {
+                "." => {}
+                ".." => {
+                    segments.pop();
+                }
+                _ => {
+                    segments.push(segment.clone());
+                    continue;
+                }
+            }
+
+            if last {
+                segments.push(String::new());
+            }
+        }
+
+        if segments.len() == 1 && segments[0].is_empty() {
+            segments.clear();
+        }
+
+        Self {
+            segments,
+            absolute: self.absolute,
+        }
+    }
+
+    pub(crate) fn merge(&self, reference: &Path) -> Self {
+        let mut segments = self.segments.clone();
+        segments.pop();
+        segments.extend(reference.segments.iter().cloned());
+
+        Self {
+            segments,
+            absolute: true,
+        }
+    }
 }
 
 impl TryFrom<&str> for Path {
     type Error = HttpError;
 
     fn try_from(src: &str) -> Result<Self, Self::Error> {
-        let mut iterator = src.split("/").map(|segment| segment.to_string());
-        iterator.next();
-        let segments = iterator.collect();
+        percent::validate(src, ErrorKind::InvalidUri, |byte| {
+            percent::is_pchar(byte) || byte == b'/'
+        })?;
+
+        let absolute = src.starts_with('/');
+        let relative_src = if absolute { &src[1..] } else { src };
+
+        if relative_src.is_empty() {
+            return Ok(Self {
+                segments: vec![],
+                absolute,
+            });
+        }
+
+        let mut segments = Vec::new();
+        let mut segment_start = src.len() - relative_src.len();
+
+        for segment in relative_src.split("/") {
+            segments.push(percent::decode(segment).map_err(|error| error.offset(segment_start))?);
+
+            segment_start += segment.len() + 1;
+        }
 
-        Ok(Self { segments })
+        Ok(Self { segments, absolute })
     }
 }
 
@@ -27,17 +98,19 @@ impl ToString for Path {
     fn to_string(&self) -> String {
         let mut data = String::new();
 
-        data += "/";
+        if self.absolute {
+            data += "/";
+        }
 
         let mut iterator = self.segments.iter();
 
         if let Some(segment) = iterator.next() {
-            data += segment.as_str();
+            data += percent::encode_path_segment(segment).as_str();
         }
 
         for segment in iterator {
             data += "/";
-            data += segment.as_str();
+            data += percent::encode_path_segment(segment).as_str();
         }
 
         data
@@ -48,14 +121,13 @@ impl ToString for Path {
 mod tests {
     use super::*;
 
-    use alloc::vec;
-
     #[test]
     fn from_str_test() {
         assert_eq!(
             Path::try_from("/resource/subresource"),
             Ok(Path {
                 segments: vec!["resource".to_string(), "subresource".to_string()],
+                absolute: true,
             })
         );
     }
@@ -65,6 +137,7 @@ mod tests {
         assert_eq!(
             Path {
                 segments: vec!["resource".to_string(), "subresource".to_string()],
+                absolute: true,
             }
             .to_string(),
             "/resource/subresource".to_string()
@@ -73,16 +146,129 @@ mod tests {
 
     #[test]
     fn from_str_empty_test() {
-        assert_eq!(Path::try_from(""), Ok(Path { segments: vec![] }));
+        assert_eq!(
+            Path::try_from(""),
+            Ok(Path {
+                segments: vec![],
+                absolute: false,
+            })
+        );
     }
 
     #[test]
     fn from_str_root_test() {
-        assert_eq!(Path::try_from("/"), Ok(Path { segments: vec![] }));
+        assert_eq!(
+            Path::try_from("/"),
+            Ok(Path {
+                segments: vec![],
+                absolute: true,
+            })
+        );
     }
 
     #[test]
     fn to_string_empty_test() {
-        assert_eq!(Path { segments: vec![] }.to_string(), "/".to_string());
+        assert_eq!(
+            Path {
+                segments: vec![],
+                absolute: true,
+            }
+            .to_string(),
+            "/".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_percent_encoded_test() {
+        assert_eq!(
+            Path::try_from("/lights/living%20room"),
+            Ok(Path {
+                segments: vec!["lights".to_string(), "living room".to_string()],
+                absolute: true,
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_percent_encoding_test() {
+        assert_eq!(
+            Path::try_from("/lights/living%2").map_err(|error| error.position),
+            Err(Some(14))
+        );
+    }
+
+    #[test]
+    fn to_string_percent_encoded_test() {
+        assert_eq!(
+            Path {
+                segments: vec!["living room".to_string(), "a/b".to_string()],
+                absolute: true,
+            }
+            .to_string(),
+            "/living%20room/a%2Fb".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_relative_test() {
+        assert_eq!(
+            Path::try_from("../config"),
+            Ok(Path {
+                segments: vec!["..".to_string(), "config".to_string()],
+                absolute: false,
+            })
+        );
+    }
+
+    #[test]
+    fn to_string_relative_test() {
+        assert_eq!(
+            Path {
+                segments: vec!["..".to_string(), "config".to_string(), "".to_string()],
+                absolute: false,
+            }
+            .to_string(),
+            "../config/".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_invalid_character_test() {
+        let error = Path::try_from("/lights/living room").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidUri);
+        assert_eq!(error.position, Some(14));
+    }
+
+    #[test]
+    fn normalize_test() {
+        assert_eq!(
+            Path::try_from("/a/b/c/./../../g").unwrap().normalize(),
+            Path::try_from("/a/g").unwrap()
+        );
+        assert_eq!(
+            Path::try_from("mid/content=5/../6").unwrap().normalize(),
+            Path::try_from("mid/6").unwrap()
+        );
+    }
+
+    #[test]
+    fn normalize_trailing_dot_segment_test() {
+        assert_eq!(
+            Path::try_from("/a/b/..").unwrap().normalize(),
+            Path::try_from("/a/").unwrap()
+        );
+        assert_eq!(
+            Path::try_from("/a/..").unwrap().normalize(),
+            Path::try_from("/").unwrap()
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_empty_segments_test() {
+        assert_eq!(
+            Path::try_from("/a//b/./").unwrap().normalize(),
+            Path::try_from("/a//b/").unwrap()
+        );
     }
 }
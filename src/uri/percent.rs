@@ -0,0 +1,194 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::error::{ErrorKind, HttpError};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+pub fn encode_userinfo(src: &str) -> String {
+    encode(src, |byte| is_unreserved(byte) || is_sub_delim(byte))
+}
+
+pub fn encode_path_segment(src: &str) -> String {
+    encode(src, is_pchar)
+}
+
+pub fn encode_query_component(src: &str) -> String {
+    encode(src, |byte| {
+        is_unreserved(byte)
+            || (is_sub_delim(byte) && !matches!(byte, b'&' | b'=' | b'+'))
+            || matches!(byte, b':' | b'@' | b'/' | b'?')
+    })
+}
+
+pub fn encode_form_component(src: &str) -> String {
+    encode(src, |byte| {
+        byte.is_ascii_alphanumeric() || matches!(byte, b'*' | b'-' | b'.' | b'_')
+    })
+    .replace("%20", "+")
+}
+
+pub fn encode_fragment(src: &str) -> String {
+    encode(src, |byte| is_pchar(byte) || matches!(byte, b'/' | b'?'))
+}
+
+pub fn decode(src: &str) -> Result<String, HttpError> {
+    let bytes = src.as_bytes();
+    let mut data = Vec::with_capacity(bytes.len());
+    let mut position = 0;
+
+    while position < bytes.len() {
+        if bytes[position] == b'%' {
+            let high = bytes.get(position + 1).and_then(|&byte| hex_value(byte));
+            let low = bytes.get(position + 2).and_then(|&byte| hex_value(byte));
+
+            if let (Some(high), Some(low)) = (high, low) {
+                data.push(high << 4 | low);
+                position += 3;
+            } else {
+                return Err(
+                    HttpError::new(ErrorKind::InvalidUri, "malformed percent-encoding")
+                        .offset(position),
+                );
+            }
+        } else {
+            data.push(bytes[position]);
+            position += 1;
+        }
+    }
+
+    String::from_utf8(data).map_err(|_| {
+        HttpError::new(
+            ErrorKind::InvalidUri,
+            "percent-encoded bytes are not valid UTF-8",
+        )
+    })
+}
+
+pub fn decode_form_component(src: &str) -> Result<String, HttpError> {
+    decode(src.replace('+', " ").as_str())
+}
+
+fn encode(src: &str, is_allowed: impl Fn(u8) -> bool) -> String {
+    let mut data = String::with_capacity(src.len());
+
+    for byte in src.bytes() {
+        if is_allowed(byte) {
+            data.push(byte as char);
+        } else {
+            data.push('%');
+            data.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+            data.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+        }
+    }
+
+    data
+}
+
+pub(crate) fn validate(
+    src: &str,
+    kind: ErrorKind,
+    is_allowed: impl Fn(u8) -> bool,
+) -> Result<(), HttpError> {
+    let bytes = src.as_bytes();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        if bytes[position] == b'%' {
+            if bytes.len() <= position + 2
+                || !bytes[position + 1].is_ascii_hexdigit()
+                || !bytes[position + 2].is_ascii_hexdigit()
+            {
+                return Err(HttpError::new(kind, "malformed percent-encoding").offset(position));
+            }
+
+            position += 3;
+        } else if is_allowed(bytes[position]) {
+            position += 1;
+        } else {
+            return Err(HttpError::new(kind, "character not allowed").offset(position));
+        }
+    }
+
+    Ok(())
+}
+
+fn hex_value(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|digit| digit as u8)
+}
+
+pub(crate) fn is_unreserved(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~')
+}
+
+pub(crate) fn is_pchar(byte: u8) -> bool {
+    is_unreserved(byte) || is_sub_delim(byte) || matches!(byte, b':' | b'@')
+}
+
+pub(crate) fn is_sub_delim(byte: u8) -> bool {
+    matches!(
+        byte,
+        b'!' | b'$' | b'&' | b'\'' | b'(' | b')' | b'*' | b'+' | b',' | b';' | b'='
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_test() {
+        assert_eq!(decode("living%20room"), Ok("living room".into()));
+        assert_eq!(decode("a%26b%2fc"), Ok("a&b/c".into()));
+        assert_eq!(decode("caf%C3%A9"), Ok("café".into()));
+    }
+
+    #[test]
+    fn decode_malformed_test() {
+        let error = decode("living%2room").unwrap_err();
+
+        assert_eq!(error.kind, ErrorKind::InvalidUri);
+        assert_eq!(error.position, Some(6));
+        assert_eq!(
+            decode("room%").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidUri)
+        );
+        assert_eq!(
+            decode("%FF").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidUri)
+        );
+    }
+
+    #[test]
+    fn encode_path_segment_test() {
+        assert_eq!(encode_path_segment("living room"), "living%20room");
+        assert_eq!(encode_path_segment("a/b?c#d"), "a%2Fb%3Fc%23d");
+        assert_eq!(encode_path_segment("user@host:1;v=2"), "user@host:1;v=2");
+        assert_eq!(encode_path_segment("café"), "caf%C3%A9");
+    }
+
+    #[test]
+    fn encode_query_component_test() {
+        assert_eq!(encode_query_component("a&b=c+d"), "a%26b%3Dc%2Bd");
+        assert_eq!(encode_query_component("/path?x"), "/path?x");
+    }
+
+    #[test]
+    fn encode_userinfo_test() {
+        assert_eq!(encode_userinfo("user:name@"), "user%3Aname%40");
+    }
+
+    #[test]
+    fn form_component_test() {
+        assert_eq!(
+            encode_form_component(" living room+1 "),
+            "+living+room%2B1+"
+        );
+        assert_eq!(
+            decode_form_component("+living+room%2B1+"),
+            Ok(" living room+1 ".into())
+        );
+    }
+}
@@ -1,78 +1,181 @@
 extern crate alloc;
 
-use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::convert::TryFrom;
+use core::str::FromStr;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
+use crate::uri::percent;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct Query {
-    pub parameters: BTreeMap<String, String>,
+    pub parameters: Vec<(String, Option<String>)>,
 }
 
-impl TryFrom<&str> for Query {
-    type Error = HttpError;
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    fn try_from(src: &str) -> Result<Self, Self::Error> {
-        let mut parameters = BTreeMap::new();
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.get_all(name).next()
+    }
+
+    pub fn get_all<'a, 'b>(&'a self, name: &'b str) -> impl Iterator<Item = &'a str> + 'b
+    where
+        'a: 'b,
+    {
+        self.parameters
+            .iter()
+            .filter(move |(parameter, _)| parameter == name)
+            .map(|(_, value)| value.as_deref().unwrap_or(""))
+    }
+
+    pub fn get_as<T: FromStr>(&self, name: &str) -> Result<Option<T>, HttpError> {
+        self.get(name)
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    HttpError::new(ErrorKind::InvalidQuery, "parameter has an unexpected type")
+                })
+            })
+            .transpose()
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.parameters
+            .iter()
+            .any(|(parameter, _)| parameter == name)
+    }
+
+    pub fn append(&mut self, name: &str, value: &str) {
+        self.parameters
+            .push((name.to_string(), Some(value.to_string())));
+    }
+
+    pub fn append_flag(&mut self, name: &str) {
+        self.parameters.push((name.to_string(), None));
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let value = self.get(name).map(|value| value.to_string());
+
+        self.parameters.retain(|(parameter, _)| parameter != name);
+
+        value
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.parameters
+            .iter()
+            .map(|(parameter, value)| (parameter.as_str(), value.as_deref()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.parameters.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parameters.is_empty()
+    }
+
+    pub fn from_form(src: &str) -> Result<Self, HttpError> {
+        Self::parse(src, percent::decode_form_component)
+    }
+
+    pub fn to_form_string(&self) -> String {
+        self.serialize(percent::encode_form_component)
+    }
+
+    fn parse(
+        src: &str,
+        decode: impl Fn(&str) -> Result<String, HttpError>,
+    ) -> Result<Self, HttpError> {
+        percent::validate(src, ErrorKind::InvalidQuery, |byte| {
+            percent::is_pchar(byte) || matches!(byte, b'/' | b'?')
+        })?;
+
+        let mut parameters = Vec::new();
+        let mut parameter_start = 0;
 
         for parameter_str in src.split("&") {
-            let parameter_parts: Vec<&str> = parameter_str.split("=").collect();
+            if !parameter_str.is_empty() {
+                let parameter = if let Some(index) = parameter_str.find('=') {
+                    let value_start = parameter_start + index + 1;
+
+                    (
+                        decode(&parameter_str[..index])
+                            .map_err(|error| error.offset(parameter_start))?,
+                        Some(
+                            decode(&parameter_str[index + 1..])
+                                .map_err(|error| error.offset(value_start))?,
+                        ),
+                    )
+                } else {
+                    (
+                        decode(parameter_str).map_err(|error| error.offset(parameter_start))?,
+                        None,
+                    )
+                };
 
-            if parameter_parts.len() != 2 {
-                return Err(HttpError::InvalidQuery);
+                parameters.push(parameter);
             }
 
-            parameters.insert(
-                parameter_parts[0].to_string(),
-                parameter_parts[1].to_string(),
-            );
+            parameter_start += parameter_str.len() + 1;
         }
 
         Ok(Self { parameters })
     }
-}
 
-impl ToString for Query {
-    fn to_string(&self) -> String {
+    fn serialize(&self, encode: impl Fn(&str) -> String) -> String {
         let mut data = String::new();
 
-        let mut iterator = self.parameters.iter();
+        for (index, (parameter, value)) in self.parameters.iter().enumerate() {
+            if index > 0 {
+                data += "&";
+            }
 
-        if let Some((parameter, value)) = iterator.next() {
-            data += parameter;
-            data += "=";
-            data += value;
-        }
+            data += encode(parameter).as_str();
 
-        for (parameter, value) in iterator {
-            data += "&";
-            data += parameter;
-            data += "=";
-            data += value;
+            if let Some(value) = value {
+                data += "=";
+                data += encode(value).as_str();
+            }
         }
 
         data
     }
 }
 
+impl TryFrom<&str> for Query {
+    type Error = HttpError;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        Self::parse(src, percent::decode)
+    }
+}
+
+impl ToString for Query {
+    fn to_string(&self) -> String {
+        self.serialize(percent::encode_query_component)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use alloc::vec;
+
     #[test]
     fn from_str_single_test() {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("parameter".to_string(), "value".to_string());
+        let parameters = vec![("parameter".to_string(), Some("value".to_string()))];
         assert_eq!(Query::try_from("parameter=value"), Ok(Query { parameters }));
     }
 
     #[test]
     fn to_string_single_test() {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("parameter".to_string(), "value".to_string());
+        let parameters = vec![("parameter".to_string(), Some("value".to_string()))];
         assert_eq!(
             Query { parameters }.to_string(),
             "parameter=value".to_string()
@@ -81,9 +184,10 @@ mod tests {
 
     #[test]
     fn from_str_multiple_test() {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("parameter1".to_string(), "value1".to_string());
-        parameters.insert("parameter2".to_string(), "value2".to_string());
+        let parameters = vec![
+            ("parameter1".to_string(), Some("value1".to_string())),
+            ("parameter2".to_string(), Some("value2".to_string())),
+        ];
         assert_eq!(
             Query::try_from("parameter1=value1&parameter2=value2"),
             Ok(Query { parameters })
@@ -92,12 +196,117 @@ mod tests {
 
     #[test]
     fn to_string_multiple_test() {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("parameter1".to_string(), "value1".to_string());
-        parameters.insert("parameter2".to_string(), "value2".to_string());
+        let parameters = vec![
+            ("parameter1".to_string(), Some("value1".to_string())),
+            ("parameter2".to_string(), Some("value2".to_string())),
+        ];
         assert_eq!(
             Query { parameters }.to_string(),
             "parameter1=value1&parameter2=value2".to_string()
         );
     }
+
+    #[test]
+    fn from_str_percent_encoded_test() {
+        let parameters = vec![
+            ("name".to_string(), Some("a&b".to_string())),
+            ("room name".to_string(), Some("a+b".to_string())),
+        ];
+        assert_eq!(
+            Query::try_from("name=a%26b&room%20name=a+b"),
+            Ok(Query { parameters })
+        );
+    }
+
+    #[test]
+    fn to_string_percent_encoded_test() {
+        let parameters = vec![("name".to_string(), Some("a&b=c d".to_string()))];
+        assert_eq!(
+            Query { parameters }.to_string(),
+            "name=a%26b%3Dc%20d".to_string()
+        );
+    }
+
+    #[test]
+    fn from_form_test() {
+        let parameters = vec![("room name".to_string(), Some("living room+1".to_string()))];
+        assert_eq!(
+            Query::from_form("room+name=living+room%2B1"),
+            Ok(Query { parameters })
+        );
+    }
+
+    #[test]
+    fn to_form_string_test() {
+        let parameters = vec![("room name".to_string(), Some("living room+1".to_string()))];
+        assert_eq!(
+            Query { parameters }.to_form_string(),
+            "room+name=living+room%2B1".to_string()
+        );
+    }
+
+    #[test]
+    fn from_str_duplicate_keys_test() {
+        let query = Query::try_from("tag=b&tag=a&level=2").unwrap();
+
+        assert_eq!(query.get("tag"), Some("b"));
+        assert_eq!(query.get_all("tag").collect::<Vec<_>>(), ["b", "a"]);
+        assert_eq!(query.to_string(), "tag=b&tag=a&level=2".to_string());
+    }
+
+    #[test]
+    fn from_str_flag_test() {
+        let query = Query::try_from("verbose&level=").unwrap();
+
+        assert_eq!(
+            query.parameters,
+            [
+                ("verbose".to_string(), None),
+                ("level".to_string(), Some("".to_string()))
+            ]
+        );
+        assert!(query.contains("verbose"));
+        assert_eq!(query.get("verbose"), Some(""));
+        assert_eq!(query.to_string(), "verbose&level=".to_string());
+    }
+
+    #[test]
+    fn from_str_equals_in_value_test() {
+        let query = Query::try_from("a=b=c").unwrap();
+
+        assert_eq!(query.get("a"), Some("b=c"));
+        assert_eq!(query.to_string(), "a=b%3Dc".to_string());
+    }
+
+    #[test]
+    fn from_str_empty_parameters_test() {
+        assert_eq!(Query::try_from("&a=1&&"), Query::try_from("a=1"));
+        assert_eq!(Query::try_from(""), Ok(Query::new()));
+    }
+
+    #[test]
+    fn append_remove_test() {
+        let mut query = Query::new();
+        query.append("tag", "a");
+        query.append_flag("verbose");
+        query.append("tag", "b");
+
+        assert_eq!(query.len(), 3);
+        assert_eq!(query.to_string(), "tag=a&verbose&tag=b".to_string());
+        assert_eq!(query.remove("tag"), Some("a".to_string()));
+        assert_eq!(query.remove("tag"), None);
+        assert_eq!(query.to_string(), "verbose".to_string());
+    }
+
+    #[test]
+    fn get_as_test() {
+        let query = Query::try_from("brightness=128&name=lamp").unwrap();
+
+        assert_eq!(query.get_as::<u16>("brightness"), Ok(Some(128)));
+        assert_eq!(query.get_as::<u16>("missing"), Ok(None));
+        assert_eq!(
+            query.get_as::<u16>("name").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidQuery)
+        );
+    }
 }
@@ -3,35 +3,91 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum Scheme {
     Http,
     Https,
+    Ws,
+    Wss,
+    Other(String),
+}
+
+impl Scheme {
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            Scheme::Http | Scheme::Ws => Some(80),
+            Scheme::Https | Scheme::Wss => Some(443),
+            Scheme::Other(_) => None,
+        }
+    }
+
+    pub fn is_secure(&self) -> bool {
+        matches!(self, Scheme::Https | Scheme::Wss)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            Scheme::Http => "http",
+            Scheme::Https => "https",
+            Scheme::Ws => "ws",
+            Scheme::Wss => "wss",
+            Scheme::Other(scheme) => scheme.as_str(),
+        }
+    }
+}
+
+impl PartialEq for Scheme {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
 }
 
 impl TryFrom<&str> for Scheme {
     type Error = HttpError;
 
     fn try_from(src: &str) -> Result<Self, Self::Error> {
-        match src {
+        if !src.starts_with(|character: char| character.is_ascii_alphabetic()) {
+            return Err(HttpError::new(
+                ErrorKind::InvalidScheme,
+                "scheme must start with a letter",
+            ));
+        }
+
+        if let Some(position) = src.bytes().position(|byte| !is_scheme_char(byte)) {
+            return Err(
+                HttpError::new(ErrorKind::InvalidScheme, "character not allowed").offset(position),
+            );
+        }
+
+        let scheme = src.to_ascii_lowercase();
+
+        match scheme.as_str() {
             "http" => Ok(Scheme::Http),
             "https" => Ok(Scheme::Https),
-            _ => Err(HttpError::InvalidScheme),
+            "ws" => Ok(Scheme::Ws),
+            "wss" => Ok(Scheme::Wss),
+            _ => Ok(Scheme::Other(scheme)),
         }
     }
 }
 
 impl ToString for Scheme {
     fn to_string(&self) -> String {
-        match self {
-            Scheme::Http => "http".to_string(),
-            Scheme::Https => "https".to_string(),
-        }
+        self.as_str().to_ascii_lowercase()
     }
 }
 
+pub(crate) fn is_scheme(src: &str) -> bool {
+    src.starts_with(|character: char| character.is_ascii_alphabetic())
+        && src.bytes().all(is_scheme_char)
+}
+
+fn is_scheme_char(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,8 +112,69 @@ mod tests {
         assert_eq!(Scheme::Https.to_string(), "https".to_string());
     }
 
+    #[test]
+    fn from_str_ws_test() {
+        assert_eq!(Scheme::try_from("ws"), Ok(Scheme::Ws));
+        assert_eq!(Scheme::try_from("wss"), Ok(Scheme::Wss));
+    }
+
+    #[test]
+    fn from_str_case_insensitive_test() {
+        assert_eq!(Scheme::try_from("HTTPS"), Ok(Scheme::Https));
+        assert_eq!(Scheme::try_from("Ws"), Ok(Scheme::Ws));
+        assert_eq!(
+            Scheme::try_from("ROSS").unwrap().to_string(),
+            "ross".to_string()
+        );
+        assert_eq!(
+            Scheme::Other("Ross".to_string()),
+            Scheme::try_from("ross").unwrap()
+        );
+    }
+
+    #[test]
+    fn from_str_other_test() {
+        assert_eq!(
+            Scheme::try_from("coap+tcp"),
+            Ok(Scheme::Other("coap+tcp".to_string()))
+        );
+        assert_eq!(
+            Scheme::try_from("ross").unwrap().to_string(),
+            "ross".to_string()
+        );
+    }
+
     #[test]
     fn from_str_invalid_scheme_test() {
-        assert_eq!(Scheme::try_from("smtp"), Err(HttpError::InvalidScheme));
+        assert_eq!(
+            Scheme::try_from("1http").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidScheme)
+        );
+        assert_eq!(
+            Scheme::try_from("").map_err(|error| error.kind),
+            Err(ErrorKind::InvalidScheme)
+        );
+
+        let error = Scheme::try_from("ro_ss").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidScheme);
+        assert_eq!(error.position, Some(2));
+    }
+
+    #[test]
+    fn default_port_test() {
+        assert_eq!(Scheme::Http.default_port(), Some(80));
+        assert_eq!(Scheme::Https.default_port(), Some(443));
+        assert_eq!(Scheme::Ws.default_port(), Some(80));
+        assert_eq!(Scheme::Wss.default_port(), Some(443));
+        assert_eq!(Scheme::Other("ross".to_string()).default_port(), None);
+    }
+
+    #[test]
+    fn is_secure_test() {
+        assert!(!Scheme::Http.is_secure());
+        assert!(Scheme::Https.is_secure());
+        assert!(!Scheme::Ws.is_secure());
+        assert!(Scheme::Wss.is_secure());
+        assert!(!Scheme::Other("ross".to_string()).is_secure());
     }
 }
@@ -3,7 +3,7 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use core::convert::TryFrom;
 
-use crate::error::HttpError;
+use crate::error::{ErrorKind, HttpError};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Version {
@@ -24,7 +24,14 @@ impl TryFrom<&str> for Version {
             "HTTP/1.1" => Ok(Version::Http11),
             "HTTP/2.0" => Ok(Version::Http20),
             "HTTP/3.0" => Ok(Version::Http30),
-            _ => Err(HttpError::InvalidVersion),
+            _ if is_version_token(src) => Err(HttpError::new(
+                ErrorKind::UnsupportedVersion,
+                "unsupported protocol version",
+            )),
+            _ => Err(HttpError::new(
+                ErrorKind::InvalidVersion,
+                "malformed protocol version",
+            )),
         }
     }
 }
@@ -41,6 +48,13 @@ impl ToString for Version {
     }
 }
 
+fn is_version_token(src: &str) -> bool {
+    match src.strip_prefix("HTTP/").map(str::as_bytes) {
+        Some([major, b'.', minor]) => major.is_ascii_digit() && minor.is_ascii_digit(),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,8 +112,20 @@ mod tests {
     #[test]
     fn from_str_invalid_version_test() {
         assert_eq!(
-            Version::try_from("HTTP/3.1"),
-            Err(HttpError::InvalidVersion)
+            Version::try_from("HTTP/3.1").map_err(|error| error.kind),
+            Err(ErrorKind::UnsupportedVersion)
         );
     }
+
+    #[test]
+    fn from_str_malformed_version_test() {
+        for src in ["HTTX/1.1", "HTTP/1", "HTTP/1.1.1", "http/1.1", "HTTP/x.1"] {
+            assert_eq!(
+                Version::try_from(src).map_err(|error| error.kind),
+                Err(ErrorKind::InvalidVersion),
+                "{}",
+                src
+            );
+        }
+    }
 }